@@ -64,7 +64,7 @@ pub type Placement = (f32, f32, f32, f32, f32, f32, f32, f32);
 // TODO: Refactor this code so that singletons and vectors of different types
 // are not duplicated
 /// A data value is a value of a field in a data block.
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum DataValue<'a> {
     String(&'a str),
     StringArray(Vec<&'a str>),
@@ -147,6 +147,11 @@ impl<'a> BsiiFile<'a> {
         self.data_blocks.iter()
     }
 
+    /// Look up a data block by its ID.
+    pub fn block_by_id(&self, id: &Id) -> Option<&DataBlock<'a>> {
+        self.data_blocks.iter().find(|block| &block.id == id)
+    }
+
     /// Iterate over blocks whose prototype has the given name.
     pub fn blocks_by_prototype_name<'data>(
         &'data self,
@@ -159,6 +164,35 @@ impl<'a> BsiiFile<'a> {
     }
 }
 
+impl Id {
+    /// Parse an ID from its textual SII representation, e.g. `null`,
+    /// `_nameless.1a2.3b4c` or `company.volatile.lkwlog.amsterdam`.
+    ///
+    /// Returns `None` if the text is not a valid ID.
+    pub fn parse(text: &str) -> Option<Self> {
+        if text == "null" {
+            return Some(Id::Named(vec![]));
+        }
+        if let Some(parts) = text.strip_prefix("_nameless.") {
+            let mut id = 0u64;
+            for part in parts.split('.') {
+                let part = u16::from_str_radix(part, 16).ok()?;
+                id = id.checked_mul(1 << 16)? | u64::from(part);
+            }
+            return Some(Id::Nameless(id));
+        }
+        let parts = text.split('.').map(ToString::to_string).collect::<Vec<_>>();
+        if parts.len() > usize::from(u8::MAX - 1)
+            || parts
+                .iter()
+                .any(|part| crate::bsii_write::encode_token(part).is_none())
+        {
+            return None;
+        }
+        Some(Id::Named(parts))
+    }
+}
+
 impl<'a> Prototype<'a> {
     /// Return the numeric prototype ID.
     pub fn id(&self) -> u32 {
@@ -189,6 +223,51 @@ impl ValuePrototype<'_> {
     pub fn type_id(&self) -> u32 {
         self.type_id
     }
+
+    /// Return a readable name for this field's type, see [`type_name`].
+    pub fn type_name(&self) -> &'static str {
+        type_name(self.type_id)
+    }
+}
+
+/// Return a readable name for a BSII type ID.
+///
+/// Names follow <https://github.com/TheLazyTomcat/SII_Decrypt/blob/master/Documents/Binary%20SII%20-%20Types.txt>,
+/// with `[]` marking array types. Unsupported type IDs are reported as `unknown`.
+pub fn type_name(type_id: u32) -> &'static str {
+    match type_id {
+        0x01 => "string",
+        0x02 => "string[]",
+        0x03 => "token",
+        0x04 => "token[]",
+        0x05 => "float",
+        0x06 => "float[]",
+        0x07 => "float2",
+        0x09 => "float3",
+        0x0a => "float3[]",
+        0x11 => "int3",
+        0x12 => "int3[]",
+        0x17 => "float4",
+        0x18 => "float4[]",
+        0x19 => "placement",
+        0x1a => "placement[]",
+        0x25 => "int32",
+        0x26 => "int32[]",
+        0x27 | 0x2f => "uint32",
+        0x28 => "uint32[]",
+        0x2b => "uint16",
+        0x2c => "uint16[]",
+        0x31 => "int64",
+        0x32 => "int64[]",
+        0x33 => "uint64",
+        0x34 => "uint64[]",
+        0x35 => "bool",
+        0x36 => "bool[]",
+        0x37 => "enum",
+        0x39 | 0x3b | 0x3d => "id",
+        0x3a | 0x3c => "id[]",
+        _ => "unknown",
+    }
 }
 
 impl<'a> DataBlock<'a> {
@@ -266,6 +345,8 @@ mod tests {
         let prototype = file.get_prototype(7).unwrap();
         assert_eq!(prototype.id(), 7);
         assert_eq!(prototype.field("cargo").unwrap().type_id(), 0x03);
+        assert_eq!(prototype.field("cargo").unwrap().type_name(), "token");
+        assert_eq!(prototype.field("revenue").unwrap().type_name(), "int32");
         assert_eq!(prototype.fields().count(), 2);
 
         let block = file
@@ -277,6 +358,8 @@ mod tests {
             Some(&DataValue::EncodedString("gravel".to_string()))
         );
         assert_eq!(block.field(&file, "missing"), None);
+        assert!(file.block_by_id(&Id::Nameless(1)).is_some());
+        assert!(file.block_by_id(&Id::Nameless(2)).is_none());
 
         let fields = block.fields(&file).unwrap().collect::<Vec<_>>();
         assert_eq!(fields.len(), 2);
@@ -307,4 +390,19 @@ mod tests {
 
         assert!(file.data_blocks[0].fields(&file).is_none());
     }
+
+    #[test]
+    fn parses_ids_from_text() {
+        for id in [
+            Id::Nameless(0x0807060504030201u64),
+            Id::Nameless(0x1234),
+            Id::Nameless(0),
+            Id::Named(vec!["company".to_string(), "volatile".to_string()]),
+            Id::Named(vec![]),
+        ] {
+            assert_eq!(Id::parse(&id.to_string()), Some(id));
+        }
+        assert_eq!(Id::parse("_nameless.xyz"), None);
+        assert_eq!(Id::parse("Company.volatile"), None);
+    }
 }
@@ -3,7 +3,7 @@ use std::{
     iter::zip,
 };
 
-use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id, Prototype, ValuePrototype};
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id, Placement, Prototype, ValuePrototype};
 
 /// Output the parsed BSII format into textual format
 /// Reference: https://modding.scssoft.com/wiki/Documentation/Engine/Units
@@ -117,19 +117,25 @@ fn write_float_vec4<W: Write>(
     write!(f, ")")
 }
 
+/// The position of a placement, with the coordinates of its cell added.
+pub(crate) fn placement_position((f1, f2, f3, f4, ..): &Placement) -> (f32, f32, f32) {
+    let coef = f4.trunc() as i32;
+    let x = f1 + (((coef & 0xfff) - 2048) << 9) as f32;
+    let z = f3 + ((((coef >> 12) & 0xfff) - 2048) << 9) as f32;
+    (x, *f2, z)
+}
+
 fn write_float_vec8<W: Write>(
     f: &mut W,
     (f1, f2, f3, f4, f5, f6, f7, f8): &(f32, f32, f32, f32, f32, f32, f32, f32),
 ) -> std::fmt::Result {
     // https://github.com/TheLazyTomcat/SII_Decrypt/blob/d1cd7921d4667de895288c7227c58df43b63bd21/Source/ValueNodes/SII_Decode_ValueNode_0000001A.pas#L124
     // https://github.com/TheLazyTomcat/SII_Decrypt/blob/d1cd7921d4667de895288c7227c58df43b63bd21/Source/ValueNodes/SII_Decode_ValueNode_00000019.pas#L57
-    let coef = f4.trunc() as i32;
-    let f1_ = f1 + (((coef & 0xfff) - 2048) << 9) as f32;
-    let f3_ = f3 + ((((coef >> 12) & 0xfff) - 2048) << 9) as f32;
+    let (f1_, f2, f3_) = placement_position(&(*f1, *f2, *f3, *f4, *f5, *f6, *f7, *f8));
     write!(f, "(")?;
     write_float(f, &f1_)?;
     write!(f, ", ")?;
-    write_float(f, f2)?;
+    write_float(f, &f2)?;
     write!(f, ", ")?;
     write_float(f, &f3_)?;
     write!(f, ") (")?;
@@ -308,6 +314,63 @@ fn write_bsii<W: Write>(f: &mut W, bsii: &BsiiFile) -> std::fmt::Result {
     Ok(())
 }
 
+fn write_plain_scalar<W: Write>(
+    f: &mut W,
+    data: &DataValue<'_>,
+    value_prototype: &ValuePrototype<'_>,
+) -> std::fmt::Result {
+    match data {
+        DataValue::String(s) => write!(f, "{}", s),
+        DataValue::EncodedString(s) => write!(f, "{}", s),
+        DataValue::Float(float) => write!(f, "{}", float),
+        DataValue::FloatVec2(data) => write_vec2(f, data, |f, float| write!(f, "{}", float)),
+        DataValue::FloatVec3(data) => write_vec3(f, data, |f, float| write!(f, "{}", float)),
+        DataValue::FloatVec4((f1, f2, f3, f4)) => write!(f, "({}; {}, {}, {})", f1, f2, f3, f4),
+        DataValue::FloatVec8(placement) => {
+            let (x, y, z) = placement_position(placement);
+            let (.., w, rx, ry, rz) = placement;
+            write!(f, "({}, {}, {}) ({}; {}, {}, {})", x, y, z, w, rx, ry, rz)
+        }
+        DataValue::Enum(e) => match value_prototype
+            .enum_values
+            .as_ref()
+            .and_then(|enum_values| enum_values.get(e))
+        {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", e),
+        },
+        _ => write_scalar_data_value(f, data, value_prototype),
+    }
+}
+
+impl DataValue<'_> {
+    /// Format a scalar value as plain text: strings without quotes or escapes,
+    /// enums by name and floats in decimal notation. Arrays are formatted as
+    /// their length, as in `to_siin_value`.
+    pub fn to_plain_text(&self, value_prototype: &ValuePrototype<'_>) -> String {
+        let mut output = String::new();
+        if let Some(length) = self.get_array_length() {
+            write!(output, "{}", length).unwrap();
+        } else {
+            write_plain_scalar(&mut output, self, value_prototype).unwrap();
+        }
+        output
+    }
+
+    /// Format the value as it appears in a SIIN file. Arrays are formatted as
+    /// their length, which is the value of the field line preceding the
+    /// elements.
+    pub fn to_siin_value(&self, value_prototype: &ValuePrototype<'_>) -> String {
+        let mut output = String::new();
+        if let Some(length) = self.get_array_length() {
+            write!(output, "{}", length).unwrap();
+        } else {
+            write_scalar_data_value(&mut output, self, value_prototype).unwrap();
+        }
+        output
+    }
+}
+
 impl BsiiFile<'_> {
     /// Write the BSII file to a SIIN string
     pub fn to_siin(&self) -> String {
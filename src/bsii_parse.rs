@@ -22,7 +22,7 @@ use crate::bsii_file::Id;
 use crate::bsii_file::Prototype;
 use crate::bsii_file::ValuePrototype;
 
-impl DataValue<'_> {
+impl<'a> DataValue<'a> {
     pub fn is_array(&self) -> bool {
         matches!(
             self,
@@ -62,6 +62,32 @@ impl DataValue<'_> {
             _ => None,
         }
     }
+
+    /// Split an array into its elements as scalar values. A scalar value is
+    /// returned as its only element.
+    pub fn elements(&self) -> Vec<DataValue<'a>> {
+        fn split<'a, T: Clone>(array: &[T], scalar: fn(T) -> DataValue<'a>) -> Vec<DataValue<'a>> {
+            array.iter().cloned().map(scalar).collect()
+        }
+
+        match self {
+            DataValue::StringArray(array) => split(array, DataValue::String),
+            DataValue::EncodedStringArray(array) => split(array, DataValue::EncodedString),
+            DataValue::FloatArray(array) => split(array, DataValue::Float),
+            DataValue::FloatVec3Array(array) => split(array, DataValue::FloatVec3),
+            DataValue::Int32Vec3Array(array) => split(array, DataValue::Int32Vec3),
+            DataValue::FloatVec4Array(array) => split(array, DataValue::FloatVec4),
+            DataValue::FloatVec8Array(array) => split(array, DataValue::FloatVec8),
+            DataValue::Int32Array(array) => split(array, DataValue::Int32),
+            DataValue::UInt32Array(array) => split(array, DataValue::UInt32),
+            DataValue::UInt16Array(array) => split(array, DataValue::UInt16),
+            DataValue::Int64Array(array) => split(array, DataValue::Int64),
+            DataValue::UInt64Array(array) => split(array, DataValue::UInt64),
+            DataValue::BoolArray(array) => split(array, DataValue::Bool),
+            DataValue::IdArray(array) => split(array, DataValue::Id),
+            scalar => vec![scalar.clone()],
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -158,7 +184,7 @@ fn str_parser(input: &[u8]) -> IResult<&[u8], &str> {
     Ok((input, str::from_utf8(data).unwrap()))
 }
 
-const CHAR_ENCODINGS: [char; 37] = [
+pub(crate) const CHAR_ENCODINGS: [char; 37] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
     'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '_',
 ];
@@ -270,6 +296,14 @@ fn value_parser(input: &[u8], type_id: u32) -> IResult<&[u8], DataValue<'_>> {
             // vec3 of float
             map(tuple((le_f32, le_f32, le_f32)), DataValue::FloatVec3)(input)
         }
+        0x0au32 => {
+            // array of vec3 of float
+            let (input, size) = le_u32(input)?;
+            map(
+                count(tuple((le_f32, le_f32, le_f32)), size as usize),
+                DataValue::FloatVec3Array,
+            )(input)
+        }
         0x11u32 => {
             // vec3 of int32
             map(tuple((le_i32, le_i32, le_i32)), DataValue::Int32Vec3)(input)
@@ -282,6 +316,13 @@ fn value_parser(input: &[u8], type_id: u32) -> IResult<&[u8], DataValue<'_>> {
                 DataValue::Int32Vec3Array,
             )(input)
         }
+        0x17u32 => {
+            // vec4 of float
+            map(
+                tuple((le_f32, le_f32, le_f32, le_f32)),
+                DataValue::FloatVec4,
+            )(input)
+        }
         0x18u32 => {
             // array of vec4 of float
             let (input, size) = le_u32(input)?;
@@ -0,0 +1,337 @@
+//! Serializes a BSII file back into the binary format.
+//!
+//! This is the inverse of `bsii_parse`. All prototypes are written before the
+//! first data block, which the format allows as long as a prototype is defined
+//! before it is used.
+//!
+//! References:
+//! <https://github.com/TheLazyTomcat/SII_Decrypt/blob/master/Documents/Binary%20SII%20-%20Format.txt>
+//! <https://github.com/TheLazyTomcat/SII_Decrypt/blob/master/Documents/Binary%20SII%20-%20Types.txt>
+
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id, Prototype, ValuePrototype};
+use crate::bsii_parse::CHAR_ENCODINGS;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WriteError {
+    /// A token contains characters outside of `[0-9a-z_]` or is too long to
+    /// fit in 64 bits.
+    InvalidToken(String),
+}
+
+impl std::fmt::Display for WriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteError::InvalidToken(token) => write!(f, "Invalid token `{}`", token),
+        }
+    }
+}
+
+impl BsiiFile<'_> {
+    /// Write the BSII file to its binary representation.
+    ///
+    /// Fails if a token or an ID part cannot be encoded, which is possible
+    /// because the fields of a parsed file can be edited freely.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WriteError> {
+        let mut output = Vec::new();
+        output.extend_from_slice(b"BSII");
+        write_u32(&mut output, self.version);
+        let mut prototypes = self.prototypes().collect::<Vec<_>>();
+        prototypes.sort_by_key(|prototype| prototype.id);
+        for prototype in prototypes {
+            write_prototype(&mut output, prototype);
+        }
+        for data_block in &self.data_blocks {
+            write_data_block(&mut output, data_block)?;
+        }
+        // End of file marker: a prototype block that is not valid
+        write_u32(&mut output, 0);
+        output.push(0);
+        Ok(output)
+    }
+}
+
+fn write_u32(output: &mut Vec<u8>, value: u32) {
+    output.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_len(output: &mut Vec<u8>, len: usize) {
+    write_u32(output, len as u32);
+}
+
+fn write_str(output: &mut Vec<u8>, value: &str) {
+    write_len(output, value.len());
+    output.extend_from_slice(value.as_bytes());
+}
+
+/// Encode a token string into its 64 bit representation.
+///
+/// Returns `None` if the string contains characters outside of `[0-9a-z_]` or
+/// is too long to fit in 64 bits.
+pub(crate) fn encode_token(value: &str) -> Option<u64> {
+    let mut encoded = 0u64;
+    for ch in value.chars().rev() {
+        let index = CHAR_ENCODINGS.iter().position(|encoding| *encoding == ch)? as u64;
+        encoded = encoded.checked_mul(38)?.checked_add(index + 1)?;
+    }
+    if encoded & (1u64 << 63) != 0 {
+        return None;
+    }
+    Some(encoded)
+}
+
+fn write_token(output: &mut Vec<u8>, value: &str) -> Result<(), WriteError> {
+    let encoded = encode_token(value).ok_or_else(|| WriteError::InvalidToken(value.to_string()))?;
+    output.extend_from_slice(&encoded.to_le_bytes());
+    Ok(())
+}
+
+fn write_id(output: &mut Vec<u8>, id: &Id) -> Result<(), WriteError> {
+    match id {
+        Id::Nameless(id) => {
+            output.push(0xff);
+            output.extend_from_slice(&id.to_le_bytes());
+        }
+        Id::Named(parts) => {
+            output.push(parts.len() as u8);
+            for part in parts {
+                write_token(output, part)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_value_prototype(output: &mut Vec<u8>, value_prototype: &ValuePrototype<'_>) {
+    write_u32(output, value_prototype.type_id);
+    write_str(output, value_prototype.name);
+    if let Some(enum_values) = &value_prototype.enum_values {
+        let mut enum_values = enum_values.iter().collect::<Vec<_>>();
+        enum_values.sort_by_key(|(value, _)| **value);
+        write_len(output, enum_values.len());
+        for (value, name) in enum_values {
+            write_u32(output, *value);
+            write_str(output, name);
+        }
+    }
+}
+
+fn write_prototype(output: &mut Vec<u8>, prototype: &Prototype<'_>) {
+    write_u32(output, 0);
+    output.push(1);
+    write_u32(output, prototype.id);
+    write_str(output, prototype.name);
+    for value_prototype in &prototype.value_prototypes {
+        write_value_prototype(output, value_prototype);
+    }
+    write_u32(output, 0);
+}
+
+fn write_array<T>(output: &mut Vec<u8>, values: &[T], write_fn: impl Fn(&mut Vec<u8>, &T)) {
+    write_len(output, values.len());
+    for value in values {
+        write_fn(output, value);
+    }
+}
+
+fn try_write_array<T>(
+    output: &mut Vec<u8>,
+    values: &[T],
+    write_fn: impl Fn(&mut Vec<u8>, &T) -> Result<(), WriteError>,
+) -> Result<(), WriteError> {
+    write_len(output, values.len());
+    for value in values {
+        write_fn(output, value)?;
+    }
+    Ok(())
+}
+
+fn write_floats(output: &mut Vec<u8>, values: &[f32]) {
+    for value in values {
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn write_ints(output: &mut Vec<u8>, values: &[i32]) {
+    for value in values {
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn write_value(output: &mut Vec<u8>, value: &DataValue<'_>) -> Result<(), WriteError> {
+    match value {
+        DataValue::String(s) => write_str(output, s),
+        DataValue::StringArray(strings) => write_array(output, strings, |o, s| write_str(o, s)),
+        DataValue::EncodedString(s) => write_token(output, s)?,
+        DataValue::EncodedStringArray(strings) => {
+            try_write_array(output, strings, |o, s| write_token(o, s))?
+        }
+        DataValue::Float(f) => write_floats(output, &[*f]),
+        DataValue::FloatArray(floats) => write_array(output, floats, |o, f| write_floats(o, &[*f])),
+        DataValue::FloatVec2((f1, f2)) => write_floats(output, &[*f1, *f2]),
+        DataValue::FloatVec3((f1, f2, f3)) => write_floats(output, &[*f1, *f2, *f3]),
+        DataValue::FloatVec3Array(vecs) => write_array(output, vecs, |o, (f1, f2, f3)| {
+            write_floats(o, &[*f1, *f2, *f3])
+        }),
+        DataValue::Int32Vec3((i1, i2, i3)) => write_ints(output, &[*i1, *i2, *i3]),
+        DataValue::Int32Vec3Array(vecs) => write_array(output, vecs, |o, (i1, i2, i3)| {
+            write_ints(o, &[*i1, *i2, *i3])
+        }),
+        DataValue::FloatVec4((f1, f2, f3, f4)) => write_floats(output, &[*f1, *f2, *f3, *f4]),
+        DataValue::FloatVec4Array(vecs) => write_array(output, vecs, |o, (f1, f2, f3, f4)| {
+            write_floats(o, &[*f1, *f2, *f3, *f4])
+        }),
+        DataValue::FloatVec8((f1, f2, f3, f4, f5, f6, f7, f8)) => {
+            write_floats(output, &[*f1, *f2, *f3, *f4, *f5, *f6, *f7, *f8])
+        }
+        DataValue::FloatVec8Array(vecs) => {
+            write_array(output, vecs, |o, (f1, f2, f3, f4, f5, f6, f7, f8)| {
+                write_floats(o, &[*f1, *f2, *f3, *f4, *f5, *f6, *f7, *f8])
+            })
+        }
+        DataValue::Int32(i) => output.extend_from_slice(&i.to_le_bytes()),
+        DataValue::Int32Array(ints) => write_array(output, ints, |o, i| write_ints(o, &[*i])),
+        DataValue::UInt32(u) => write_u32(output, *u),
+        DataValue::UInt32Array(uints) => write_array(output, uints, |o, u| write_u32(o, *u)),
+        DataValue::UInt16(u) => output.extend_from_slice(&u.to_le_bytes()),
+        DataValue::UInt16Array(uints) => {
+            write_array(output, uints, |o, u| o.extend_from_slice(&u.to_le_bytes()))
+        }
+        DataValue::Int64(i) => output.extend_from_slice(&i.to_le_bytes()),
+        DataValue::Int64Array(ints) => {
+            write_array(output, ints, |o, i| o.extend_from_slice(&i.to_le_bytes()))
+        }
+        DataValue::UInt64(u) => output.extend_from_slice(&u.to_le_bytes()),
+        DataValue::UInt64Array(uints) => {
+            write_array(output, uints, |o, u| o.extend_from_slice(&u.to_le_bytes()))
+        }
+        DataValue::Bool(b) => output.push(u8::from(*b)),
+        DataValue::BoolArray(bools) => write_array(output, bools, |o, b| o.push(u8::from(*b))),
+        DataValue::Enum(e) => write_u32(output, *e),
+        DataValue::Id(id) => write_id(output, id)?,
+        DataValue::IdArray(ids) => try_write_array(output, ids, write_id)?,
+    }
+    Ok(())
+}
+
+fn write_data_block(output: &mut Vec<u8>, data_block: &DataBlock<'_>) -> Result<(), WriteError> {
+    write_u32(output, data_block.prototype_id);
+    write_id(output, &data_block.id)?;
+    for value in &data_block.data {
+        write_value(output, value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn encodes_tokens() {
+        assert_eq!(encode_token(""), Some(0));
+        assert_eq!(encode_token("a"), Some(11));
+        assert_eq!(encode_token("lkwlog"), encode_token("lkwlog"));
+        assert_eq!(encode_token("Upper"), None);
+        assert_eq!(encode_token("waytoolongtoken"), None);
+    }
+
+    #[test]
+    fn round_trips_through_parser() {
+        let file = BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "player",
+                    value_prototypes: vec![
+                        ValuePrototype {
+                            type_id: 0x01,
+                            name: "name",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x04,
+                            name: "cities",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x37,
+                            name: "state",
+                            enum_values: Some(HashMap::from([(0, "idle"), (1, "driving")])),
+                        },
+                        ValuePrototype {
+                            type_id: 0x3a,
+                            name: "trucks",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x19,
+                            name: "placement",
+                            enum_values: None,
+                        },
+                    ],
+                },
+            )]),
+            data_blocks: vec![DataBlock {
+                prototype_id: 1,
+                id: Id::Named(vec!["player".to_string()]),
+                data: vec![
+                    DataValue::String("Driver \"One\""),
+                    DataValue::EncodedStringArray(vec!["berlin".to_string(), "a_b".to_string()]),
+                    DataValue::Enum(1),
+                    DataValue::IdArray(vec![
+                        Id::Nameless(0x1234),
+                        Id::Named(vec!["vehicle".to_string(), "scania".to_string()]),
+                    ]),
+                    DataValue::FloatVec8((1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0)),
+                ],
+            }],
+        };
+
+        let bytes = file.to_bytes().unwrap();
+        let parsed = BsiiFile::parse(&bytes).unwrap();
+
+        assert_eq!(parsed.to_siin(), file.to_siin());
+        assert_eq!(parsed.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn rejects_invalid_tokens() {
+        let mut file = BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "player",
+                    value_prototypes: vec![ValuePrototype {
+                        type_id: 0x03,
+                        name: "city",
+                        enum_values: None,
+                    }],
+                },
+            )]),
+            data_blocks: vec![DataBlock {
+                prototype_id: 1,
+                id: Id::Named(vec!["Player".to_string()]),
+                data: vec![DataValue::EncodedString("berlin".to_string())],
+            }],
+        };
+
+        assert_eq!(
+            file.to_bytes(),
+            Err(WriteError::InvalidToken("Player".to_string()))
+        );
+        file.data_blocks[0].id = Id::Named(vec!["player".to_string()]);
+        file.data_blocks[0].data[0] = DataValue::EncodedString("Den Haag".to_string());
+        assert_eq!(
+            file.to_bytes(),
+            Err(WriteError::InvalidToken("Den Haag".to_string()))
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! CityHash64 v1.1, used by HashFS archives to hash entry paths.
+//!
+//! Reference: <https://github.com/google/cityhash/blob/master/src/city.cc>
+
+const K0: u64 = 0xc3a5c85c97cb3127;
+const K1: u64 = 0xb492b66fbe98f273;
+const K2: u64 = 0x9ae16a3b2f90404f;
+const K_MUL: u64 = 0x9ddfea08eb382d69;
+
+pub(crate) fn city_hash_64(s: &[u8]) -> u64 {
+    let len = s.len();
+    if len <= 16 {
+        return hash_len_0_to_16(s);
+    }
+    if len <= 32 {
+        return hash_len_17_to_32(s);
+    }
+    if len <= 64 {
+        return hash_len_33_to_64(s);
+    }
+
+    let mut x = fetch_64(&s[len - 40..]);
+    let mut y = fetch_64(&s[len - 16..]).wrapping_add(fetch_64(&s[len - 56..]));
+    let mut z = hash_len_16(
+        fetch_64(&s[len - 48..]).wrapping_add(len as u64),
+        fetch_64(&s[len - 24..]),
+    );
+    let mut v = weak_hash_len_32_with_seeds(&s[len - 64..], len as u64, z);
+    let mut w = weak_hash_len_32_with_seeds(&s[len - 32..], y.wrapping_add(K1), x);
+    x = x.wrapping_mul(K1).wrapping_add(fetch_64(s));
+
+    for chunk in s[..(len - 1) & !63].chunks_exact(64) {
+        x = x
+            .wrapping_add(y)
+            .wrapping_add(v.0)
+            .wrapping_add(fetch_64(&chunk[8..]))
+            .rotate_right(37)
+            .wrapping_mul(K1);
+        y = y
+            .wrapping_add(v.1)
+            .wrapping_add(fetch_64(&chunk[48..]))
+            .rotate_right(42)
+            .wrapping_mul(K1);
+        x ^= w.1;
+        y = y.wrapping_add(v.0).wrapping_add(fetch_64(&chunk[40..]));
+        z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+        v = weak_hash_len_32_with_seeds(chunk, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        w = weak_hash_len_32_with_seeds(
+            &chunk[32..],
+            z.wrapping_add(w.1),
+            y.wrapping_add(fetch_64(&chunk[16..])),
+        );
+        std::mem::swap(&mut z, &mut x);
+    }
+
+    hash_len_16(
+        hash_len_16(v.0, w.0)
+            .wrapping_add(shift_mix(y).wrapping_mul(K1))
+            .wrapping_add(z),
+        hash_len_16(v.1, w.1).wrapping_add(x),
+    )
+}
+
+fn fetch_64(s: &[u8]) -> u64 {
+    u64::from_le_bytes(s[..8].try_into().unwrap())
+}
+
+fn fetch_32(s: &[u8]) -> u64 {
+    u64::from(u32::from_le_bytes(s[..4].try_into().unwrap()))
+}
+
+fn shift_mix(value: u64) -> u64 {
+    value ^ (value >> 47)
+}
+
+fn hash_len_16(u: u64, v: u64) -> u64 {
+    hash_len_16_mul(u, v, K_MUL)
+}
+
+fn hash_len_16_mul(u: u64, v: u64, mul: u64) -> u64 {
+    let a = shift_mix((u ^ v).wrapping_mul(mul));
+    let b = shift_mix((v ^ a).wrapping_mul(mul));
+    b.wrapping_mul(mul)
+}
+
+fn hash_len_0_to_16(s: &[u8]) -> u64 {
+    let len = s.len();
+    if len >= 8 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch_64(s).wrapping_add(K2);
+        let b = fetch_64(&s[len - 8..]);
+        let c = b.rotate_right(37).wrapping_mul(mul).wrapping_add(a);
+        let d = a.rotate_right(25).wrapping_add(b).wrapping_mul(mul);
+        hash_len_16_mul(c, d, mul)
+    } else if len >= 4 {
+        let mul = K2.wrapping_add(len as u64 * 2);
+        let a = fetch_32(s);
+        hash_len_16_mul(len as u64 + (a << 3), fetch_32(&s[len - 4..]), mul)
+    } else if len > 0 {
+        let y = u32::from(s[0]) + (u32::from(s[len >> 1]) << 8);
+        let z = len as u32 + (u32::from(s[len - 1]) << 2);
+        shift_mix(u64::from(y).wrapping_mul(K2) ^ u64::from(z).wrapping_mul(K0)).wrapping_mul(K2)
+    } else {
+        K2
+    }
+}
+
+fn hash_len_17_to_32(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch_64(s).wrapping_mul(K1);
+    let b = fetch_64(&s[8..]);
+    let c = fetch_64(&s[len - 8..]).wrapping_mul(mul);
+    let d = fetch_64(&s[len - 16..]).wrapping_mul(K2);
+    hash_len_16_mul(
+        a.wrapping_add(b)
+            .rotate_right(43)
+            .wrapping_add(c.rotate_right(30))
+            .wrapping_add(d),
+        a.wrapping_add(b.wrapping_add(K2).rotate_right(18))
+            .wrapping_add(c),
+        mul,
+    )
+}
+
+fn hash_len_33_to_64(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch_64(s).wrapping_mul(K2);
+    let b = fetch_64(&s[8..]);
+    let c = fetch_64(&s[len - 24..]);
+    let d = fetch_64(&s[len - 32..]);
+    let e = fetch_64(&s[16..]).wrapping_mul(K2);
+    let f = fetch_64(&s[24..]).wrapping_mul(9);
+    let g = fetch_64(&s[len - 8..]);
+    let h = fetch_64(&s[len - 16..]).wrapping_mul(mul);
+    let u = a
+        .wrapping_add(g)
+        .rotate_right(43)
+        .wrapping_add(b.rotate_right(30).wrapping_add(c).wrapping_mul(9));
+    let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+    let w = u
+        .wrapping_add(v)
+        .wrapping_mul(mul)
+        .swap_bytes()
+        .wrapping_add(h);
+    let x = e.wrapping_add(f).rotate_right(42).wrapping_add(c);
+    let y = v
+        .wrapping_add(w)
+        .wrapping_mul(mul)
+        .swap_bytes()
+        .wrapping_add(g)
+        .wrapping_mul(mul);
+    let z = e.wrapping_add(f).wrapping_add(c);
+    let a = x
+        .wrapping_add(z)
+        .wrapping_mul(mul)
+        .wrapping_add(y)
+        .swap_bytes()
+        .wrapping_add(b);
+    let b = shift_mix(
+        z.wrapping_add(a)
+            .wrapping_mul(mul)
+            .wrapping_add(d)
+            .wrapping_add(h),
+    )
+    .wrapping_mul(mul);
+    b.wrapping_add(x)
+}
+
+fn weak_hash_len_32_with_seeds(s: &[u8], a: u64, b: u64) -> (u64, u64) {
+    let w = fetch_64(s);
+    let x = fetch_64(&s[8..]);
+    let y = fetch_64(&s[16..]);
+    let z = fetch_64(&s[24..]);
+    let a = a.wrapping_add(w);
+    let b = b.wrapping_add(a).wrapping_add(z).rotate_right(21);
+    let c = a;
+    let a = a.wrapping_add(x).wrapping_add(y);
+    let b = b.wrapping_add(a.rotate_right(44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The test data of the reference implementation, `city-test.cc`.
+    fn test_data() -> Vec<u8> {
+        let mut data = vec![0; 201 * 201];
+        let (mut a, mut b) = (9u64, 777u64);
+        for (index, byte) in data.iter_mut().enumerate() {
+            a = a.wrapping_add(b);
+            b = b.wrapping_add(a);
+            a = (a ^ (a >> 41)).wrapping_mul(K0);
+            b = (b ^ (b >> 41)).wrapping_mul(K0).wrapping_add(index as u64);
+            *byte = (b >> 37) as u8;
+        }
+        data
+    }
+
+    #[test]
+    fn matches_reference_hashes() {
+        let data = test_data();
+        let hash = |len: usize| city_hash_64(&data[len * len..len * len + len]);
+
+        assert_eq!(city_hash_64(b""), 0x9ae16a3b2f90404f);
+        assert_eq!(hash(1), 0x541150e87f415e96);
+        assert_eq!(hash(6), 0x3eca803e70304894);
+        assert_eq!(hash(13), 0x851fff285561dca0);
+        assert_eq!(hash(25), 0xdc78cb032c49217);
+        assert_eq!(hash(50), 0x9a87bea227491d20);
+        assert_eq!(hash(64), 0xe88419922b87176f);
+        assert_eq!(hash(65), 0x105191e0ec8f7f60);
+        assert_eq!(hash(200), 0x7fc98006e25cac9);
+    }
+}
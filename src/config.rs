@@ -0,0 +1,249 @@
+//! Parses and writes the `uset` format of game config files like `config.cfg`
+//! and `config_local.cfg`.
+//!
+//! Every setting is a line `uset <name> "<value>"`. Comments, empty lines and
+//! other commands are kept verbatim, so that writing a parsed file only
+//! changes the lines of settings which were set. The format has no escapes,
+//! so values containing quotes or line breaks cannot be set.
+//!
+//! `controls.sii` is a SII file despite holding similar settings, and is
+//! decoded like any other SII file.
+
+const USET: &str = "uset";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    InvalidLine { line: usize, message: String },
+    InvalidSetting { name: String, message: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::InvalidLine { line, message } => write!(f, "Line {}: {}", line, message),
+            ConfigError::InvalidSetting { name, message } => write!(f, "{}: {}", name, message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A parsed config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    lines: Vec<Line>,
+    line_ending: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Line {
+    Setting {
+        name: String,
+        value: String,
+        /// The original line, cleared when the value is changed.
+        raw: Option<String>,
+    },
+    Other(String),
+}
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let line_ending = if content.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let lines = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parse_line(line).map_err(|message| ConfigError::InvalidLine {
+                    line: index + 1,
+                    message,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { lines, line_ending })
+    }
+
+    /// The value of a setting. If a setting is written more than once, the
+    /// last value wins, as in the game.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.settings()
+            .filter(|(setting, _)| *setting == name)
+            .last()
+            .map(|(_, value)| value)
+    }
+
+    /// All settings in file order.
+    pub fn settings(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Setting { name, value, .. } => Some((name.as_str(), value.as_str())),
+            Line::Other(_) => None,
+        })
+    }
+
+    /// Change every line of a setting, or append it to the end of the file.
+    ///
+    /// Names must not be empty or contain whitespace or quotes, and values
+    /// must not contain quotes or line breaks, so that the written line can
+    /// be parsed again.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), ConfigError> {
+        let invalid = |message: &str| ConfigError::InvalidSetting {
+            name: name.to_string(),
+            message: message.to_string(),
+        };
+        if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == '"') {
+            return Err(invalid("invalid setting name"));
+        }
+        if value.contains(['"', '\r', '\n']) {
+            return Err(invalid("values cannot contain quotes or line breaks"));
+        }
+        let mut found = false;
+        for line in &mut self.lines {
+            if let Line::Setting {
+                name: setting,
+                value: old_value,
+                raw,
+            } = line
+            {
+                if setting == name {
+                    found = true;
+                    if old_value != value {
+                        *old_value = value.to_string();
+                        *raw = None;
+                    }
+                }
+            }
+        }
+        if !found {
+            self.lines.push(Line::Setting {
+                name: name.to_string(),
+                value: value.to_string(),
+                raw: None,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            match line {
+                Line::Setting { raw: Some(raw), .. } | Line::Other(raw) => write!(f, "{raw}")?,
+                Line::Setting { name, value, .. } => write!(f, "{USET} {name} \"{value}\"")?,
+            }
+            write!(f, "{}", self.line_ending)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Line, String> {
+    let mut parts = line.trim().splitn(2, char::is_whitespace);
+    if parts.next() != Some(USET) {
+        return Ok(Line::Other(line.to_string()));
+    }
+    let rest = parts.next().unwrap_or_default().trim_start();
+    let (name, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    if name.is_empty() {
+        return Err("missing setting name".to_string());
+    }
+    let value = value.trim();
+    let value = match value.strip_prefix('"') {
+        Some(quoted) => quoted
+            .strip_suffix('"')
+            .ok_or_else(|| "unterminated value".to_string())?,
+        None => value,
+    };
+    Ok(Line::Setting {
+        name: name.to_string(),
+        value: value.to_string(),
+        raw: Some(line.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "# prism3d variable config data\r\n\r\nuset g_developer \"0\"\r\nuset g_lang  \"en_us\"\r\nuset g_console 0\r\n";
+
+    #[test]
+    fn reads_settings() {
+        let config = Config::parse(CONFIG).unwrap();
+
+        assert_eq!(
+            config.settings().collect::<Vec<_>>(),
+            [
+                ("g_developer", "0"),
+                ("g_lang", "en_us"),
+                ("g_console", "0")
+            ]
+        );
+        assert_eq!(config.get("g_lang"), Some("en_us"));
+        assert_eq!(config.get("g_save_format"), None);
+    }
+
+    #[test]
+    fn writes_changed_settings_only() {
+        let mut config = Config::parse(CONFIG).unwrap();
+        config.set("g_lang", "en_us").unwrap();
+        config.set("g_developer", "1").unwrap();
+        config.set("g_save_format", "2").unwrap();
+
+        assert_eq!(
+            config.to_string(),
+            "# prism3d variable config data\r\n\r\nuset g_developer \"1\"\r\nuset g_lang  \"en_us\"\r\nuset g_console 0\r\nuset g_save_format \"2\"\r\n"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert_eq!(
+            Config::parse("uset g_lang \"en_us\nuset\n"),
+            Err(ConfigError::InvalidLine {
+                line: 1,
+                message: "unterminated value".to_string(),
+            })
+        );
+        assert!(matches!(
+            Config::parse("uset g_lang \"en_us\"\nuset\n"),
+            Err(ConfigError::InvalidLine { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn round_trips_set_values() {
+        let mut config = Config::parse(CONFIG).unwrap();
+        config.set("g_lang", "de_de").unwrap();
+        config.set("g_profile", "My Profile 'ETS2'").unwrap();
+
+        let parsed = Config::parse(&config.to_string()).unwrap();
+
+        assert_eq!(
+            parsed.settings().collect::<Vec<_>>(),
+            config.settings().collect::<Vec<_>>()
+        );
+        assert_eq!(parsed.get("g_profile"), Some("My Profile 'ETS2'"));
+    }
+
+    #[test]
+    fn rejects_values_that_cannot_be_written() {
+        let mut config = Config::parse(CONFIG).unwrap();
+
+        for (name, value) in [
+            ("g_lang", "en\"us"),
+            ("g_lang", "en\nuset g_developer 1"),
+            ("g lang", "en_us"),
+            ("", "en_us"),
+        ] {
+            assert!(matches!(
+                config.set(name, value),
+                Err(ConfigError::InvalidSetting { .. })
+            ));
+        }
+        assert_eq!(config, Config::parse(CONFIG).unwrap());
+    }
+}
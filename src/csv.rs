@@ -0,0 +1,200 @@
+//! Exports the units of a prototype as CSV.
+
+use crate::bsii_file::{BsiiFile, DataBlock, ValuePrototype};
+
+/// Separator between the elements of an array joined into a single cell.
+pub const JOIN_SEPARATOR: &str = "|";
+
+/// How array fields are laid out in the exported table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArrayLayout {
+    /// One cell per array, with the elements joined by `JOIN_SEPARATOR`.
+    #[default]
+    Joined,
+    /// One column per array index, named `field[index]`, with as many columns
+    /// as the longest array of the field.
+    Flattened,
+}
+
+/// Export all units of a prototype as CSV, with one row per unit and one
+/// column per field after the unit ID. Values are formatted as plain text,
+/// see `DataValue::to_plain_text`.
+///
+/// Returns `None` if the file has no prototype with the given name.
+pub fn prototype_to_csv(bsii: &BsiiFile<'_>, name: &str, layout: ArrayLayout) -> Option<String> {
+    let prototype = bsii.prototypes().find(|prototype| prototype.name == name)?;
+    let blocks = bsii
+        .data_blocks()
+        .filter(|block| block.prototype_id == prototype.id())
+        .collect::<Vec<_>>();
+    let columns = prototype
+        .value_prototypes
+        .iter()
+        .enumerate()
+        .map(|(index, value_prototype)| Column {
+            index,
+            value_prototype,
+            width: match layout {
+                ArrayLayout::Flattened if is_array_field(value_prototype) => blocks
+                    .iter()
+                    .filter_map(|block| block.data.get(index)?.get_array_length())
+                    .max()
+                    .or(Some(0)),
+                _ => None,
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = String::new();
+    let mut header = vec!["id".to_string()];
+    for column in &columns {
+        let name = column.value_prototype.name;
+        match column.width {
+            Some(width) => header.extend((0..width).map(|index| format!("{name}[{index}]"))),
+            None => header.push(name.to_string()),
+        }
+    }
+    write_csv_row(&mut output, &header);
+    for block in blocks {
+        let mut row = vec![block.id.to_string()];
+        for column in &columns {
+            column.write_cells(&mut row, block);
+        }
+        write_csv_row(&mut output, &row);
+    }
+    Some(output)
+}
+
+struct Column<'a, 'file> {
+    index: usize,
+    value_prototype: &'a ValuePrototype<'file>,
+    /// The number of columns of a flattened array.
+    width: Option<usize>,
+}
+
+impl Column<'_, '_> {
+    fn write_cells(&self, row: &mut Vec<String>, block: &DataBlock<'_>) {
+        let Some(value) = block.data.get(self.index) else {
+            row.extend(std::iter::repeat_n(String::new(), self.width.unwrap_or(1)));
+            return;
+        };
+        let elements = value
+            .elements()
+            .iter()
+            .map(|element| element.to_plain_text(self.value_prototype))
+            .collect::<Vec<_>>();
+        match self.width {
+            Some(width) => {
+                let start = row.len();
+                row.extend(elements);
+                row.resize(start + width, String::new());
+            }
+            None if value.is_array() => row.push(elements.join(JOIN_SEPARATOR)),
+            None => row.extend(elements),
+        }
+    }
+}
+
+fn is_array_field(value_prototype: &ValuePrototype<'_>) -> bool {
+    crate::siin_value::array_element_type_id(value_prototype.type_id).is_some()
+}
+
+/// Append a row to the CSV output, quoting cells that need it.
+pub(crate) fn write_csv_row(output: &mut String, cells: &[String]) {
+    for (index, cell) in cells.iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            output.push('"');
+            output.push_str(&cell.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(cell);
+        }
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::bsii_file::{DataValue, Id, Prototype};
+
+    fn sample() -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "garage",
+                    value_prototypes: vec![
+                        ValuePrototype {
+                            type_id: 0x3a,
+                            name: "vehicles",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x01,
+                            name: "note",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x05,
+                            name: "productivity",
+                            enum_values: None,
+                        },
+                    ],
+                },
+            )]),
+            data_blocks: vec![
+                DataBlock {
+                    prototype_id: 1,
+                    id: Id::Named(vec!["garage".to_string(), "berlin".to_string()]),
+                    data: vec![
+                        DataValue::IdArray(vec![Id::Nameless(0x1234), Id::Named(vec![])]),
+                        DataValue::String("big, \"busy\""),
+                        DataValue::Float(0.5),
+                    ],
+                },
+                DataBlock {
+                    prototype_id: 1,
+                    id: Id::Named(vec!["garage".to_string(), "paris".to_string()]),
+                    data: vec![
+                        DataValue::IdArray(vec![]),
+                        DataValue::String(""),
+                        DataValue::Float(1.0),
+                    ],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn exports_units_with_joined_arrays() {
+        let csv = prototype_to_csv(&sample(), "garage", ArrayLayout::Joined).unwrap();
+
+        assert_eq!(
+            csv,
+            "id,vehicles,note,productivity\n\
+             garage.berlin,_nameless.1234|null,\"big, \"\"busy\"\"\",0.5\n\
+             garage.paris,,,1\n"
+        );
+    }
+
+    #[test]
+    fn exports_units_with_flattened_arrays() {
+        let csv = prototype_to_csv(&sample(), "garage", ArrayLayout::Flattened).unwrap();
+
+        assert_eq!(
+            csv.lines().next(),
+            Some("id,vehicles[0],vehicles[1],note,productivity")
+        );
+        assert_eq!(csv.lines().nth(2), Some("garage.paris,,,,1"));
+        assert!(prototype_to_csv(&sample(), "vehicle", ArrayLayout::Flattened).is_none());
+    }
+}
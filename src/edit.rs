@@ -0,0 +1,319 @@
+//! Edits field values of units in a BSII file.
+//!
+//! Fields are addressed by a path of the form `<unit-id>.<field>`, or
+//! `<unit-id>.<field>[<index>]` for a single element of an array field, e.g.
+//! `_nameless.1a2.3b4c.money_account` or `economy.visited_cities[0]`. Values
+//! are written in the same textual form used by SiiN files and are parsed
+//! according to the field's type.
+
+use crate::bsii_file::{type_name, BsiiFile, Id};
+use crate::file_type::{decode_until_bsii, detect_file_type, encode_bsii, DecodeError, FileType};
+use crate::siin_value::{array_element_type_id, parse_scalar, set_array_element};
+
+#[derive(Debug)]
+pub enum EditError {
+    /// Error when the input file cannot be decoded to BSII.
+    Decode(DecodeError),
+    /// Error when the field path is malformed.
+    InvalidPath(String),
+    /// Error when no unit has the given ID.
+    UnknownUnit(String),
+    /// Error when the unit does not have the given field.
+    UnknownField { unit: String, field: String },
+    /// Error when an index is given for a field that is not an array.
+    NotAnArray(String),
+    /// Error when an array index is out of bounds.
+    IndexOutOfBounds { field: String, index: usize },
+    /// Error when an array field is set without an index.
+    MissingIndex(String),
+    /// Error when the value cannot be parsed as the field's type.
+    InvalidValue {
+        field: String,
+        type_name: &'static str,
+        value: String,
+    },
+}
+
+impl From<DecodeError> for EditError {
+    fn from(err: DecodeError) -> Self {
+        EditError::Decode(err)
+    }
+}
+
+impl std::fmt::Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditError::Decode(err) => write!(f, "{}", err),
+            EditError::InvalidPath(path) => {
+                write!(
+                    f,
+                    "Invalid field path `{}`, expected <unit-id>.<field>",
+                    path
+                )
+            }
+            EditError::UnknownUnit(unit) => write!(f, "Unknown unit `{}`", unit),
+            EditError::UnknownField { unit, field } => {
+                write!(f, "Unit `{}` has no field `{}`", unit, field)
+            }
+            EditError::NotAnArray(field) => write!(f, "Field `{}` is not an array", field),
+            EditError::IndexOutOfBounds { field, index } => {
+                write!(f, "Index {} is out of bounds for field `{}`", index, field)
+            }
+            EditError::MissingIndex(field) => write!(
+                f,
+                "Field `{}` is an array, an element index like `{}[0]` is required",
+                field, field
+            ),
+            EditError::InvalidValue {
+                field,
+                type_name,
+                value,
+            } => write!(
+                f,
+                "Invalid value `{}` for field `{}` of type {}",
+                value, field, type_name
+            ),
+        }
+    }
+}
+
+/// A parsed field path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldPath {
+    pub unit: Id,
+    pub field: String,
+    pub index: Option<usize>,
+}
+
+impl FieldPath {
+    pub fn parse(path: &str) -> Result<Self, EditError> {
+        let invalid = || EditError::InvalidPath(path.to_string());
+        let (unit, field) = path.rsplit_once('.').ok_or_else(invalid)?;
+        let unit = Id::parse(unit).ok_or_else(invalid)?;
+        let (field, index) = match field.strip_suffix(']') {
+            Some(field) => {
+                let (field, index) = field.split_once('[').ok_or_else(invalid)?;
+                (field, Some(index.parse().map_err(|_| invalid())?))
+            }
+            None => (field, None),
+        };
+        if field.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            unit,
+            field: field.to_string(),
+            index,
+        })
+    }
+}
+
+/// Set a field of a unit to a value given in its textual form.
+pub fn set_field<'a>(
+    bsii: &mut BsiiFile<'a>,
+    path: &FieldPath,
+    value: &'a str,
+) -> Result<(), EditError> {
+    let block = bsii
+        .data_blocks
+        .iter_mut()
+        .find(|block| block.id == path.unit)
+        .ok_or_else(|| EditError::UnknownUnit(path.unit.to_string()))?;
+    let unknown_field = || EditError::UnknownField {
+        unit: path.unit.to_string(),
+        field: path.field.clone(),
+    };
+    let prototype = bsii
+        .prototypes
+        .get(&block.prototype_id)
+        .ok_or_else(unknown_field)?;
+    let field_index = prototype
+        .field_index(&path.field)
+        .ok_or_else(unknown_field)?;
+    let value_prototype = &prototype.value_prototypes[field_index];
+    let data = block.data.get_mut(field_index).ok_or_else(unknown_field)?;
+
+    let element_type_id = array_element_type_id(value_prototype.type_id);
+    let type_id = match (element_type_id, path.index) {
+        (Some(element_type_id), Some(_)) => element_type_id,
+        (Some(_), None) => return Err(EditError::MissingIndex(path.field.clone())),
+        (None, Some(_)) => return Err(EditError::NotAnArray(path.field.clone())),
+        (None, None) => value_prototype.type_id,
+    };
+    let parsed =
+        parse_scalar(type_id, value_prototype.enum_values.as_ref(), value).ok_or_else(|| {
+            EditError::InvalidValue {
+                field: path.field.clone(),
+                type_name: type_name(type_id),
+                value: value.to_string(),
+            }
+        })?;
+    match path.index {
+        Some(index) => {
+            if !set_array_element(data, index, parsed) {
+                return Err(EditError::IndexOutOfBounds {
+                    field: path.field.clone(),
+                    index,
+                });
+            }
+        }
+        None => *data = parsed,
+    }
+    Ok(())
+}
+
+/// Set a field in a supported file and encode the result.
+///
+/// The result is written in `output_type`, or in the container format of the
+/// input (BSII or ScsC) if no type is given.
+pub fn set_field_in_file(
+    content: &[u8],
+    path: &str,
+    value: &str,
+    output_type: Option<FileType>,
+) -> Result<Vec<u8>, EditError> {
+    let path = FieldPath::parse(path)?;
+    let output_type = output_type
+        .or_else(|| detect_file_type(content))
+        .ok_or(DecodeError::UnknownFileType)?;
+    let bsii_content = decode_until_bsii(content)?;
+    let mut bsii = BsiiFile::parse(bsii_content.as_ref()).map_err(DecodeError::from)?;
+    set_field(&mut bsii, &path, value)?;
+    Ok(encode_bsii(&bsii, output_type)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::bsii_file::{DataBlock, DataValue, Prototype, ValuePrototype};
+    use crate::scsc_file::ScscFile;
+
+    #[test]
+    fn parses_field_paths() {
+        assert_eq!(
+            FieldPath::parse("_nameless.1a2.3b4c.money_account").unwrap(),
+            FieldPath {
+                unit: Id::Nameless(0x1a23b4c),
+                field: "money_account".to_string(),
+                index: None,
+            }
+        );
+        assert_eq!(
+            FieldPath::parse("economy.visited_cities[2]").unwrap(),
+            FieldPath {
+                unit: Id::Named(vec!["economy".to_string()]),
+                field: "visited_cities".to_string(),
+                index: Some(2),
+            }
+        );
+        assert!(FieldPath::parse("money_account").is_err());
+        assert!(FieldPath::parse("economy.cities[x]").is_err());
+    }
+
+    #[test]
+    fn sets_scalar_and_array_fields() {
+        let mut bsii = bsii();
+
+        set_field(&mut bsii, &path("bank.money_account"), "250000").unwrap();
+        set_field(&mut bsii, &path("bank.loans[1]"), "_nameless.5").unwrap();
+
+        assert_eq!(bsii.data_blocks[0].data[0], DataValue::Int64(250000));
+        assert_eq!(
+            bsii.data_blocks[0].data[1],
+            DataValue::IdArray(vec![Id::Nameless(1), Id::Nameless(5)])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_edits() {
+        let mut bsii = bsii();
+
+        assert!(matches!(
+            set_field(&mut bsii, &path("garage.money_account"), "1"),
+            Err(EditError::UnknownUnit(_))
+        ));
+        assert!(matches!(
+            set_field(&mut bsii, &path("bank.missing"), "1"),
+            Err(EditError::UnknownField { .. })
+        ));
+        assert!(matches!(
+            set_field(&mut bsii, &path("bank.money_account"), "lots"),
+            Err(EditError::InvalidValue {
+                type_name: "int64",
+                ..
+            })
+        ));
+        assert!(matches!(
+            set_field(&mut bsii, &path("bank.loans"), "null"),
+            Err(EditError::MissingIndex(_))
+        ));
+        assert!(matches!(
+            set_field(&mut bsii, &path("bank.money_account[0]"), "1"),
+            Err(EditError::NotAnArray(_))
+        ));
+        assert!(matches!(
+            set_field(&mut bsii, &path("bank.loans[2]"), "null"),
+            Err(EditError::IndexOutOfBounds { index: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn writes_result_in_original_container() {
+        let input = bsii().to_bytes().unwrap();
+        let output = set_field_in_file(&input, "bank.money_account", "7", None).unwrap();
+        let parsed = BsiiFile::parse(&output).unwrap();
+        assert_eq!(parsed.data_blocks[0].data[0], DataValue::Int64(7));
+
+        let scsc = crate::scsc_file::encode(&input);
+        let output = set_field_in_file(&scsc, "bank.money_account", "8", None).unwrap();
+        let decoded = ScscFile::parse(&output).unwrap().decode().unwrap();
+        let parsed = BsiiFile::parse(&decoded).unwrap();
+        assert_eq!(parsed.data_blocks[0].data[0], DataValue::Int64(8));
+
+        let output =
+            set_field_in_file(&input, "bank.money_account", "9", Some(FileType::Siin)).unwrap();
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("money_account: 9\n"));
+    }
+
+    fn path(path: &str) -> FieldPath {
+        FieldPath::parse(path).unwrap()
+    }
+
+    fn bsii() -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "bank",
+                    value_prototypes: vec![
+                        ValuePrototype {
+                            type_id: 0x31,
+                            name: "money_account",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x3a,
+                            name: "loans",
+                            enum_values: None,
+                        },
+                    ],
+                },
+            )]),
+            data_blocks: vec![DataBlock {
+                prototype_id: 1,
+                id: Id::Named(vec!["bank".to_string()]),
+                data: vec![
+                    DataValue::Int64(1000),
+                    DataValue::IdArray(vec![Id::Nameless(1), Id::Nameless(2)]),
+                ],
+            }],
+        }
+    }
+}
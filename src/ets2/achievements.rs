@@ -60,6 +60,44 @@ pub struct AchievementEvidence {
     pub complete: bool,
 }
 
+/// An achievement whose progress or status differs between two registries.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AchievementChange {
+    pub id: &'static str,
+    pub display_name: &'static str,
+    pub previous_status: AchievementStatus,
+    pub previous: AchievementProgress,
+    pub status: AchievementStatus,
+    pub progress: AchievementProgress,
+}
+
+impl AchievementRegistry {
+    /// List the achievements that changed compared to an earlier evaluation,
+    /// e.g. of a previous save of the same profile.
+    pub fn changes_since(&self, previous: &AchievementRegistry) -> Vec<AchievementChange> {
+        self.achievements
+            .iter()
+            .filter_map(|achievement| {
+                let before = previous
+                    .achievements
+                    .iter()
+                    .find(|before| before.id == achievement.id)?;
+                if before.status == achievement.status && before.progress == achievement.progress {
+                    return None;
+                }
+                Some(AchievementChange {
+                    id: achievement.id,
+                    display_name: achievement.display_name,
+                    previous_status: before.status,
+                    previous: before.progress.clone(),
+                    status: achievement.status,
+                    progress: achievement.progress.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
 pub fn evaluate_achievements(save: &SaveGame) -> AchievementRegistry {
     AchievementRegistry {
         achievements: vec![
@@ -587,9 +625,34 @@ mod tests {
     fn save_with_entries(entries: Vec<DeliveryLogEntry>) -> SaveGame {
         SaveGame {
             delivery_log: DeliveryLog::from_entries(entries),
+            ..SaveGame::default()
         }
     }
 
+    #[test]
+    fn lists_changed_achievements_since_previous_save() {
+        let previous = evaluate_achievements(&save_with_entries(vec![entry(
+            "cargo.gravel",
+            1500,
+            "vehicle.man.tgx",
+            "cargo",
+        )]));
+        let current = evaluate_achievements(&save_with_entries(vec![
+            entry("cargo.gravel", 1500, "vehicle.man.tgx", "cargo"),
+            entry("cargo.gravel", 2500, "vehicle.man.tgx", "cargo"),
+        ]));
+
+        let changes = current.changes_since(&previous);
+
+        assert_eq!(
+            changes.iter().map(|change| change.id).collect::<Vec<_>>(),
+            ["long_hauler", "profit_hunter"]
+        );
+        assert_eq!(changes[0].previous_status, AchievementStatus::InProgress);
+        assert_eq!(changes[0].status, AchievementStatus::Complete);
+        assert!(current.changes_since(&current).is_empty());
+    }
+
     fn entry(cargo: &str, distance_km: u32, truck: &str, job_type: &str) -> DeliveryLogEntry {
         DeliveryLogEntry {
             source_company: "company.volatile.lkwlog.amsterdam".to_string(),
@@ -599,6 +662,7 @@ mod tests {
             revenue: 1000.0,
             truck: truck.to_string(),
             job_type: job_type.to_string(),
+            params: vec![],
         }
     }
 
@@ -1,15 +1,18 @@
 use crate::bsii_file::BsiiFile;
 use crate::ets2::{
     evaluate_achievements, Achievement, AchievementEvidence, AchievementRegistry,
-    AchievementStatus, DeliveryAnalytics, SaveGame,
+    AchievementStatus, Bank, Completion, DeliveryAnalytics, Discovery, Driver, Garage,
+    PlayerProgress, Profile, SaveGame,
 };
 use crate::file_type::decode_until_bsii;
+use crate::json::{json_escape, render_string_iter};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AnalyzeError {
     Decode(String),
     BsiiParse(String),
     SaveGame(String),
+    Profile(String),
 }
 
 impl std::fmt::Display for AnalyzeError {
@@ -18,6 +21,7 @@ impl std::fmt::Display for AnalyzeError {
             AnalyzeError::Decode(message) => write!(f, "decode error: {message}"),
             AnalyzeError::BsiiParse(message) => write!(f, "BSII parse error: {message}"),
             AnalyzeError::SaveGame(message) => write!(f, "save game error: {message}"),
+            AnalyzeError::Profile(message) => write!(f, "profile error: {message}"),
         }
     }
 }
@@ -25,20 +29,65 @@ impl std::fmt::Display for AnalyzeError {
 impl std::error::Error for AnalyzeError {}
 
 pub fn analyze_save_to_json(input: &[u8]) -> Result<String, AnalyzeError> {
+    let save = load_save_game(input)?;
+    Ok(render_analysis_json(&save))
+}
+
+/// Decode a save file of any supported type into a `SaveGame`.
+pub fn load_save_game(input: &[u8]) -> Result<SaveGame, AnalyzeError> {
+    with_bsii(input, |bsii| {
+        SaveGame::from_bsii(bsii).map_err(|err| AnalyzeError::SaveGame(err.to_string()))
+    })
+}
+
+/// Decode a `profile.sii` file of any supported type into a `Profile`.
+pub fn load_profile(input: &[u8]) -> Result<Profile, AnalyzeError> {
+    with_bsii(input, |bsii| {
+        Profile::from_bsii(bsii).map_err(|err| AnalyzeError::Profile(err.to_string()))
+    })
+}
+
+fn with_bsii<T>(
+    input: &[u8],
+    read: impl FnOnce(&BsiiFile<'_>) -> Result<T, AnalyzeError>,
+) -> Result<T, AnalyzeError> {
     let bsii_content =
         decode_until_bsii(input).map_err(|err| AnalyzeError::Decode(err.to_string()))?;
     let bsii = BsiiFile::parse(bsii_content.as_ref())
         .map_err(|err| AnalyzeError::BsiiParse(err.to_string()))?;
-    let save = SaveGame::from_bsii(&bsii).map_err(|err| AnalyzeError::SaveGame(err.to_string()))?;
-    Ok(render_analysis_json(&save))
+    read(&bsii)
 }
 
-fn render_analysis_json(save: &SaveGame) -> String {
+/// Render the analysis JSON produced by `analyze_save_to_json`.
+pub fn render_analysis_json(save: &SaveGame) -> String {
     let analytics = save.analytics();
     let registry = evaluate_achievements(save);
     let mut output = String::from("{\n");
     output.push_str("  \"analytics\": ");
     output.push_str(&render_analytics(&analytics));
+    output.push_str(",\n  \"finances\": ");
+    output.push_str(&match &save.bank {
+        Some(bank) => render_finances(bank, analytics.total_revenue),
+        None => "null".to_string(),
+    });
+    output.push_str(",\n  \"progress\": ");
+    output.push_str(&match &save.progress {
+        Some(progress) => render_progress(progress),
+        None => "null".to_string(),
+    });
+    output.push_str(",\n  \"discovery\": ");
+    output.push_str(&match &save.discovery {
+        Some(discovery) => render_discovery(discovery),
+        None => "null".to_string(),
+    });
+    output.push_str(",\n  \"garages\": ");
+    output.push_str(&render_garage_ranking(&Garage::rank_by_earnings(
+        &save.garages,
+    )));
+    output.push_str(",\n  \"drivers\": ");
+    output.push_str(&render_driver_leaderboard(&Driver::leaderboard(
+        &save.drivers,
+    )));
     output.push_str(",\n  \"achievements\": ");
     output.push_str(&render_achievement_registry(&registry));
     output.push_str("\n}\n");
@@ -83,6 +132,119 @@ fn render_analytics(analytics: &DeliveryAnalytics) -> String {
     output
 }
 
+fn render_finances(bank: &Bank, delivery_revenue: f64) -> String {
+    format!(
+        "{{\n    \"money_account\": {},\n    \"loan_count\": {},\n    \"total_debt\": {},\n    \"net_worth\": {},\n    \"delivery_revenue\": {}\n  }}",
+        bank.money_account,
+        bank.loans.len(),
+        bank.total_debt(),
+        bank.net_worth(),
+        render_number(delivery_revenue)
+    )
+}
+
+fn render_progress(progress: &PlayerProgress) -> String {
+    let skills = &progress.skills;
+    format!(
+        "{{\n    \"experience_points\": {},\n    \"level\": {},\n    \"experience_to_next_level\": {},\n    \"unspent_skill_points\": {},\n    \"skills\": {{ \"adr_mask\": {}, \"long_distance\": {}, \"high_value\": {}, \"fragile\": {}, \"urgent\": {}, \"mechanical\": {} }}\n  }}",
+        progress.experience_points,
+        progress.level(),
+        progress.experience_to_next_level(),
+        progress.unspent_skill_points(),
+        skills.adr,
+        skills.long_distance,
+        skills.high_value,
+        skills.fragile,
+        skills.urgent,
+        skills.mechanical
+    )
+}
+
+fn render_discovery(discovery: &Discovery) -> String {
+    let render_completion = |completion: Completion| {
+        format!(
+            "{{ \"visited\": {}, \"total\": {} }}",
+            completion.visited, completion.total
+        )
+    };
+    let countries = discovery
+        .completion_by_country()
+        .into_iter()
+        .map(|(country, completion)| {
+            format!(
+                "\"{}\": {}",
+                json_escape(country),
+                render_completion(completion)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{{\n    \"cities\": {},\n    \"countries\": {{{countries}}},\n    \"visited_cities\": {},\n    \"unlocked_dealer_count\": {},\n    \"unlocked_recruitment_count\": {},\n    \"discovered_viewpoint_count\": {},\n    \"photo_trophy_count\": {}\n  }}",
+        render_completion(discovery.city_completion()),
+        render_string_iter(discovery.visited_cities.iter().map(String::as_str)),
+        discovery.unlocked_dealers.len(),
+        discovery.unlocked_recruitments.len(),
+        discovery.discovered_viewpoints.len(),
+        discovery.photo_trophies.len()
+    )
+}
+
+fn render_garage_ranking(garages: &[&Garage]) -> String {
+    if garages.is_empty() {
+        return "[]".to_string();
+    }
+    let garages = garages
+        .iter()
+        .map(|garage| {
+            format!(
+                "    {{ \"id\": \"{}\", \"city\": \"{}\", \"vehicle_count\": {}, \"driver_count\": {}, \"revenue\": {}, \"expenses\": {}, \"profit\": {} }}",
+                json_escape(&garage.id),
+                json_escape(&garage.city),
+                garage.vehicles.len(),
+                garage.drivers.len(),
+                garage.revenue(),
+                garage.expenses(),
+                garage.profit()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!("[\n{garages}\n  ]")
+}
+
+fn render_driver_leaderboard(drivers: &[&Driver]) -> String {
+    if drivers.is_empty() {
+        return "[]".to_string();
+    }
+    let drivers = drivers
+        .iter()
+        .map(|driver| {
+            let skills = &driver.skills;
+            format!(
+                "    {{ \"id\": \"{}\", \"hometown\": {}, \"experience_points\": {}, \"skills\": {{ \"adr_mask\": {}, \"long_distance\": {}, \"high_value\": {}, \"fragile\": {}, \"urgent\": {}, \"mechanical\": {} }}, \"distance\": {}, \"revenue\": {}, \"profit\": {} }}",
+                json_escape(&driver.id),
+                match &driver.hometown {
+                    Some(hometown) => format!("\"{}\"", json_escape(hometown)),
+                    None => "null".to_string(),
+                },
+                driver.experience_points,
+                skills.adr,
+                skills.long_distance,
+                skills.high_value,
+                skills.fragile,
+                skills.urgent,
+                skills.mechanical,
+                driver.distance(),
+                driver.revenue(),
+                driver.profit()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!("[\n{drivers}\n  ]")
+}
+
 fn render_achievement_registry(registry: &AchievementRegistry) -> String {
     let achievements = registry
         .achievements
@@ -130,14 +292,6 @@ fn render_evidence(evidence: &[AchievementEvidence]) -> String {
     format!("[{evidence}]")
 }
 
-fn render_string_iter<'a>(values: impl Iterator<Item = &'a str>) -> String {
-    let values = values
-        .map(|value| format!("\"{}\"", json_escape(value)))
-        .collect::<Vec<_>>()
-        .join(", ");
-    format!("[{values}]")
-}
-
 fn render_usize_map(map: &std::collections::BTreeMap<String, usize>) -> String {
     let fields = map
         .iter()
@@ -164,30 +318,15 @@ fn render_number(value: f64) -> String {
     }
 }
 
-fn json_escape(value: &str) -> String {
-    let mut escaped = String::new();
-    for ch in value.chars() {
-        match ch {
-            '"' => escaped.push_str("\\\""),
-            '\\' => escaped.push_str("\\\\"),
-            '\n' => escaped.push_str("\\n"),
-            '\r' => escaped.push_str("\\r"),
-            '\t' => escaped.push_str("\\t"),
-            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
-            ch => escaped.push(ch),
-        }
-    }
-    escaped
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ets2::{DeliveryLog, DeliveryLogEntry};
+    use crate::ets2::{
+        BankLoan, DeliveryLog, DeliveryLogEntry, DriverSkills, PlayerSkills, ProfitLogEntry,
+    };
 
-    #[test]
-    fn renders_analysis_json() {
-        let save = SaveGame {
+    fn sample_save() -> SaveGame {
+        SaveGame {
             delivery_log: DeliveryLog::from_entries(vec![DeliveryLogEntry {
                 source_company: "company.volatile.lkwlog.amsterdam".to_string(),
                 destination_company: "company.volatile.stokes.amsterdam".to_string(),
@@ -196,14 +335,161 @@ mod tests {
                 revenue: 16930.0,
                 truck: "vehicle.mercedes.actros".to_string(),
                 job_type: "cargo".to_string(),
+                params: vec![],
             }]),
-        };
+            ..SaveGame::default()
+        }
+    }
 
-        let json = render_analysis_json(&save);
+    #[test]
+    fn renders_analysis_json() {
+        let json = render_analysis_json(&sample_save());
 
         assert!(json.contains("\"delivery_count\": 1"));
         assert!(json.contains("\"total_distance_km\": 362"));
+        assert!(json.contains("\"finances\": null"));
+        assert!(json.contains("\"progress\": null"));
+        assert!(json.contains("\"discovery\": null"));
+        assert!(json.contains("\"garages\": [],"));
+        assert!(json.contains("\"drivers\": [],"));
         assert!(json.contains("\"id\": \"experience_beats_all\""));
         assert!(json.contains("\"id\": \"test_drive_limited\""));
     }
+
+    #[test]
+    fn renders_finances_next_to_revenue() {
+        let save = SaveGame {
+            bank: Some(Bank {
+                money_account: 50_000,
+                coinsurance_fixed: None,
+                coinsurance_ratio: None,
+                loan_limit: None,
+                overdraft: false,
+                loans: vec![BankLoan {
+                    amount: 20_000,
+                    original_amount: 30_000,
+                    interest_rate: 0.1,
+                    duration: None,
+                    time_left: 10,
+                }],
+            }),
+            ..sample_save()
+        };
+
+        let json = render_analysis_json(&save);
+
+        assert!(json.contains(
+            "  \"finances\": {\n    \"money_account\": 50000,\n    \"loan_count\": 1,\n    \"total_debt\": 20000,\n    \"net_worth\": 30000,\n    \"delivery_revenue\": 16930.0\n  },\n"
+        ));
+    }
+
+    #[test]
+    fn renders_garages_by_earnings() {
+        let garage = |city: &str, status, revenue| Garage {
+            id: format!("garage.{city}"),
+            city: city.to_string(),
+            status,
+            slot_count: 1,
+            vehicles: vec!["_nameless.100".to_string()],
+            drivers: vec![],
+            trailers: vec![],
+            productivity: None,
+            profit_log: vec![ProfitLogEntry {
+                revenue,
+                wage: 100,
+                maintenance: 0,
+                fuel: 0,
+                distance: 300,
+                cargo_count: 1,
+                timestamp_day: None,
+            }],
+        };
+        let save = SaveGame {
+            garages: vec![
+                garage("berlin", 1, 1_000),
+                garage("praha", 3, 2_000),
+                garage("wien", 0, 0),
+            ],
+            ..sample_save()
+        };
+
+        let json = render_analysis_json(&save);
+
+        assert!(json.contains(
+            "  \"garages\": [\n    { \"id\": \"garage.praha\", \"city\": \"praha\", \"vehicle_count\": 1, \"driver_count\": 0, \"revenue\": 2000, \"expenses\": 100, \"profit\": 1900 },\n    { \"id\": \"garage.berlin\""
+        ));
+        assert!(!json.contains("garage.wien"));
+    }
+
+    #[test]
+    fn renders_driver_leaderboard() {
+        let driver = |id: &str, revenue| Driver {
+            id: id.to_string(),
+            hometown: Some("berlin".to_string()),
+            current_city: None,
+            experience_points: 1_000,
+            skills: DriverSkills {
+                adr: 1,
+                ..DriverSkills::default()
+            },
+            assigned_truck: None,
+            assigned_trailer: None,
+            current_job: None,
+            profit_log: vec![ProfitLogEntry {
+                revenue,
+                wage: 200,
+                maintenance: 0,
+                fuel: 100,
+                distance: 400,
+                cargo_count: 1,
+                timestamp_day: None,
+            }],
+        };
+        let save = SaveGame {
+            drivers: vec![driver("driver.otto", 800), driver("driver.anna", 1_500)],
+            ..sample_save()
+        };
+
+        let json = render_analysis_json(&save);
+
+        assert!(json.contains(
+            "  \"drivers\": [\n    { \"id\": \"driver.anna\", \"hometown\": \"berlin\", \"experience_points\": 1000, \"skills\": { \"adr_mask\": 1, \"long_distance\": 0, \"high_value\": 0, \"fragile\": 0, \"urgent\": 0, \"mechanical\": 0 }, \"distance\": 400, \"revenue\": 1500, \"profit\": 1200 },\n    { \"id\": \"driver.otto\""
+        ));
+    }
+
+    #[test]
+    fn renders_player_progress() {
+        let save = SaveGame {
+            progress: Some(PlayerProgress {
+                experience_points: 650,
+                skills: PlayerSkills {
+                    adr: 0b1,
+                    ..PlayerSkills::default()
+                },
+            }),
+            ..sample_save()
+        };
+
+        let json = render_analysis_json(&save);
+
+        assert!(json.contains(
+            "  \"progress\": {\n    \"experience_points\": 650,\n    \"level\": 3,\n    \"experience_to_next_level\": 250,\n    \"unspent_skill_points\": 1,\n    \"skills\": { \"adr_mask\": 1, \"long_distance\": 0, \"high_value\": 0, \"fragile\": 0, \"urgent\": 0, \"mechanical\": 0 }\n  },\n"
+        ));
+    }
+
+    #[test]
+    fn renders_discovery() {
+        let save = SaveGame {
+            discovery: Some(Discovery {
+                visited_cities: vec!["berlin".to_string()],
+                unlocked_dealers: vec!["scania_berlin".to_string()],
+                ..Discovery::default()
+            }),
+            ..sample_save()
+        };
+
+        let json = render_analysis_json(&save);
+
+        assert!(json.contains("    \"visited_cities\": [\"berlin\"],\n    \"unlocked_dealer_count\": 1,\n    \"unlocked_recruitment_count\": 0,\n"));
+    }
 }
@@ -0,0 +1,166 @@
+use crate::bsii_file::{BsiiFile, DataBlock};
+use crate::ets2::fields::{boolean, float, integer, Units};
+
+/// The player's bank account, read from the `bank` unit and its `bank_loan`
+/// units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bank {
+    /// The balance, negative when the account is overdrawn.
+    pub money_account: i64,
+    /// The fixed part of the insurance deductible.
+    pub coinsurance_fixed: Option<i64>,
+    /// The part of the damage cost paid on top of `coinsurance_fixed`.
+    pub coinsurance_ratio: Option<f64>,
+    /// The maximum total amount of all loans.
+    pub loan_limit: Option<i64>,
+    pub overdraft: bool,
+    pub loans: Vec<BankLoan>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BankLoan {
+    /// The amount left to pay back.
+    pub amount: i64,
+    pub original_amount: i64,
+    pub interest_rate: f64,
+    /// The duration of the loan in days.
+    pub duration: Option<i64>,
+    /// The days left until the loan is paid back.
+    pub time_left: i64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BankError {
+    MissingBank,
+}
+
+impl std::fmt::Display for BankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BankError::MissingBank => write!(f, "missing bank block"),
+        }
+    }
+}
+
+impl std::error::Error for BankError {}
+
+impl Bank {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, BankError> {
+        Self::from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn from_units(units: &Units<'_, '_>) -> Result<Self, BankError> {
+        let bsii = units.bsii;
+        let block = bsii
+            .blocks_by_prototype_name("bank")
+            .next()
+            .ok_or(BankError::MissingBank)?;
+        Ok(Self {
+            money_account: integer(bsii, block, "money_account").unwrap_or(0),
+            coinsurance_fixed: integer(bsii, block, "coinsurance_fixed"),
+            coinsurance_ratio: float(bsii, block, "coinsurance_ratio"),
+            loan_limit: integer(bsii, block, "loan_limit"),
+            overdraft: boolean(bsii, block, "overdraft").unwrap_or(false),
+            loans: units
+                .references(block, "loans")
+                .into_iter()
+                .map(|loan| BankLoan::from_block(bsii, loan))
+                .collect(),
+        })
+    }
+
+    /// The amount left to pay back on all loans.
+    pub fn total_debt(&self) -> i64 {
+        self.loans.iter().map(|loan| loan.amount).sum()
+    }
+
+    /// The balance minus the debt. Trucks, trailers and garages are not
+    /// included.
+    pub fn net_worth(&self) -> i64 {
+        self.money_account - self.total_debt()
+    }
+}
+
+impl BankLoan {
+    fn from_block(bsii: &BsiiFile<'_>, block: &DataBlock<'_>) -> Self {
+        Self {
+            amount: integer(bsii, block, "amount").unwrap_or(0),
+            original_amount: integer(bsii, block, "original_amount").unwrap_or(0),
+            interest_rate: float(bsii, block, "interest_rate").unwrap_or(0.0),
+            duration: integer(bsii, block, "duration"),
+            time_left: integer(bsii, block, "time_left").unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{unit_ids, SaveBuilder};
+
+    fn loan(
+        amount: u32,
+        original_amount: u32,
+        time_left: u32,
+    ) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("amount", DataValue::UInt32(amount)),
+            ("original_amount", DataValue::UInt32(original_amount)),
+            ("time_left", DataValue::UInt32(time_left)),
+            ("interest_rate", DataValue::Float(0.25)),
+            ("duration", DataValue::UInt32(60)),
+        ]
+    }
+
+    #[test]
+    fn reads_bank_and_loans() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "bank",
+                "bank.player",
+                vec![
+                    ("money_account", DataValue::Int64(-1_500)),
+                    ("coinsurance_fixed", DataValue::UInt32(2_000)),
+                    ("coinsurance_ratio", DataValue::Float(0.5)),
+                    (
+                        "loans",
+                        unit_ids(&["_nameless.10", "_nameless.11", "_nameless.12"]),
+                    ),
+                    ("loan_limit", DataValue::UInt32(400_000)),
+                    ("overdraft", DataValue::Bool(true)),
+                ],
+            )
+            .unit("bank_loan", "_nameless.10", loan(90_000, 100_000, 55))
+            .unit("bank_loan", "_nameless.11", loan(20_000, 20_000, 30))
+            .build();
+
+        let bank = Bank::from_bsii(&bsii).unwrap();
+
+        assert_eq!(bank.money_account, -1_500);
+        assert_eq!(bank.coinsurance_fixed, Some(2_000));
+        assert_eq!(bank.loan_limit, Some(400_000));
+        assert!(bank.overdraft);
+        assert_eq!(bank.loans.len(), 2);
+        assert_eq!(
+            bank.loans[0],
+            BankLoan {
+                amount: 90_000,
+                original_amount: 100_000,
+                interest_rate: 0.25,
+                duration: Some(60),
+                time_left: 55,
+            }
+        );
+        assert_eq!(bank.total_debt(), 110_000);
+        assert_eq!(bank.net_worth(), -111_500);
+    }
+
+    #[test]
+    fn requires_bank() {
+        assert_eq!(
+            Bank::from_bsii(&SaveBuilder::new().build()),
+            Err(BankError::MissingBank)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::bsii_file::BsiiFile;
+use crate::ets2::fields::{integer, string, Units};
+use crate::ets2::generated::cargo_metadata::CARGOS;
+use crate::ets2::job_market::split_company;
+use crate::ets2::save::cargo_id;
+use crate::ets2::CargoMetadata;
+
+/// The job the player is on, read from the `player_job` unit referenced by
+/// the `current_job` field of the `player` unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentJob {
+    /// The cargo ID without prefix, e.g. `apples`.
+    pub cargo: String,
+    pub source_company: String,
+    pub source_city: String,
+    pub target_company: String,
+    pub target_city: String,
+    pub planned_distance_km: i64,
+    /// The urgency of the job, missing for jobs without a deadline.
+    pub urgency: Option<i64>,
+    pub units_count: i64,
+    /// The in-game time in minutes when the cargo is due.
+    pub deadline: Option<i64>,
+    /// The in-game minutes left until the deadline when the save was taken,
+    /// negative when the job is late.
+    pub time_left: Option<i64>,
+    /// The trailer of the job: the company trailer of a freight market job or
+    /// the player's own trailer.
+    pub trailer: Option<String>,
+    /// The metadata of the cargo, missing for cargos of mods.
+    pub metadata: Option<&'static CargoMetadata>,
+}
+
+impl CurrentJob {
+    /// Read the current job, or `None` if the player is not on a job.
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Option<Self> {
+        Self::from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn from_units(units: &Units<'_, '_>) -> Option<Self> {
+        let bsii = units.bsii;
+        let player = bsii.blocks_by_prototype_name("player").next()?;
+        let job = units.reference(player, "current_job")?;
+        let cargo = cargo_id(&string(bsii, job, "cargo")?).to_string();
+        let (source_company, source_city) =
+            split_company(&string(bsii, job, "source_company").unwrap_or_default());
+        let (target_company, target_city) =
+            split_company(&string(bsii, job, "target_company").unwrap_or_default());
+        let deadline = integer(bsii, job, "time_upper_limit");
+        let game_time = bsii
+            .blocks_by_prototype_name("economy")
+            .next()
+            .and_then(|economy| integer(bsii, economy, "game_time"));
+        Some(Self {
+            source_company,
+            source_city,
+            target_company,
+            target_city,
+            metadata: CARGOS.iter().find(|metadata| metadata.id == cargo),
+            cargo,
+            planned_distance_km: integer(bsii, job, "planned_distance_km").unwrap_or(0),
+            urgency: integer(bsii, job, "urgency"),
+            units_count: integer(bsii, job, "units_count").unwrap_or(1),
+            deadline,
+            time_left: deadline
+                .zip(game_time)
+                .map(|(deadline, game_time)| deadline - game_time),
+            trailer: string(bsii, job, "company_trailer")
+                .or_else(|| string(bsii, player, "assigned_trailer")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{unit_id, SaveBuilder};
+
+    fn player_job(company_trailer: &str) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("company_truck", DataValue::Id(unit_id("null"))),
+            ("company_trailer", DataValue::Id(unit_id(company_trailer))),
+            (
+                "source_company",
+                DataValue::Id(unit_id("company.volatile.tesco.berlin")),
+            ),
+            (
+                "target_company",
+                DataValue::Id(unit_id("company.volatile.kaarfor.praha")),
+            ),
+            ("cargo", DataValue::Id(unit_id("cargo.acid"))),
+            ("urgency", DataValue::UInt32(1)),
+            ("units_count", DataValue::UInt32(12)),
+            ("planned_distance_km", DataValue::UInt32(351)),
+            ("time_upper_limit", DataValue::UInt32(7_000)),
+        ]
+    }
+
+    fn sample_bsii(current_job: &str, company_trailer: &str) -> BsiiFile<'static> {
+        SaveBuilder::new()
+            .unit(
+                "economy",
+                "_nameless.1",
+                vec![("game_time", DataValue::UInt32(6_400))],
+            )
+            .unit(
+                "player",
+                "player",
+                vec![
+                    ("current_job", DataValue::Id(unit_id(current_job))),
+                    ("assigned_trailer", DataValue::Id(unit_id("_nameless.300"))),
+                ],
+            )
+            .unit("player_job", "_nameless.50", player_job(company_trailer))
+            .build()
+    }
+
+    #[test]
+    fn reads_current_job() {
+        let job = CurrentJob::from_bsii(&sample_bsii("_nameless.50", "_nameless.60")).unwrap();
+
+        assert_eq!(job.cargo, "acid");
+        assert_eq!(job.metadata.map(|metadata| metadata.id), Some("acid"));
+        assert_eq!(
+            (job.source_company.as_str(), job.source_city.as_str()),
+            ("tesco", "berlin")
+        );
+        assert_eq!(
+            (job.target_company.as_str(), job.target_city.as_str()),
+            ("kaarfor", "praha")
+        );
+        assert_eq!(job.planned_distance_km, 351);
+        assert_eq!(job.urgency, Some(1));
+        assert_eq!(job.units_count, 12);
+        assert_eq!(job.trailer.as_deref(), Some("_nameless.60"));
+        assert_eq!(job.deadline, Some(7_000));
+        assert_eq!(job.time_left, Some(600));
+    }
+
+    #[test]
+    fn uses_own_trailer_without_company_trailer() {
+        let job = CurrentJob::from_bsii(&sample_bsii("_nameless.50", "null")).unwrap();
+
+        assert_eq!(job.trailer.as_deref(), Some("_nameless.300"));
+    }
+
+    #[test]
+    fn reads_no_job() {
+        assert_eq!(CurrentJob::from_bsii(&sample_bsii("null", "null")), None);
+        assert_eq!(CurrentJob::from_bsii(&SaveBuilder::new().build()), None);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use crate::bsii_file::BsiiFile;
+use crate::ets2::generated::city_metadata::CITIES;
+use crate::ets2::{CityMetadata, Economy, EconomyError};
+
+/// The places discovered by the player, read from the `economy` unit.
+///
+/// Viewpoints and photo trophies are only written by newer game versions and
+/// are empty otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Discovery {
+    /// The visited cities, e.g. `berlin`.
+    pub visited_cities: Vec<String>,
+    pub unlocked_dealers: Vec<String>,
+    pub unlocked_recruitments: Vec<String>,
+    pub discovered_viewpoints: Vec<String>,
+    pub photo_trophies: Vec<String>,
+}
+
+/// The visited cities of a country against all cities of the map metadata.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Completion {
+    pub visited: usize,
+    pub total: usize,
+}
+
+impl Discovery {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, EconomyError> {
+        Economy::from_bsii(bsii).map(|economy| Self::from_economy(&economy))
+    }
+
+    pub fn from_economy(economy: &Economy) -> Self {
+        Self {
+            visited_cities: economy.visited_cities.clone(),
+            unlocked_dealers: economy.unlocked_dealers.clone(),
+            unlocked_recruitments: economy.unlocked_recruitments.clone(),
+            discovered_viewpoints: economy.discovered_viewpoints.clone(),
+            photo_trophies: economy.photo_trophies.clone(),
+        }
+    }
+
+    /// The visited cities against all cities of the map metadata. Cities
+    /// missing from the metadata, e.g. from map mods, are not counted.
+    pub fn city_completion(&self) -> Completion {
+        completion(&self.visited_cities, CITIES.iter())
+    }
+
+    /// The city completion of each country of the map metadata, e.g.
+    /// `germany`.
+    pub fn completion_by_country(&self) -> BTreeMap<&'static str, Completion> {
+        completion_by_country(&self.visited_cities, CITIES)
+    }
+}
+
+fn completion<'a>(
+    visited_cities: &[String],
+    cities: impl Iterator<Item = &'a CityMetadata>,
+) -> Completion {
+    let mut completion = Completion::default();
+    for city in cities {
+        completion.total += 1;
+        if visited_cities.iter().any(|visited| visited == city.id) {
+            completion.visited += 1;
+        }
+    }
+    completion
+}
+
+fn completion_by_country(
+    visited_cities: &[String],
+    cities: &'static [CityMetadata],
+) -> BTreeMap<&'static str, Completion> {
+    let mut countries = BTreeMap::<_, Vec<_>>::new();
+    for city in cities {
+        countries.entry(city.country).or_default().push(city);
+    }
+    countries
+        .into_iter()
+        .map(|(country, cities)| (country, completion(visited_cities, cities.into_iter())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ets2::test_support::{tokens, SaveBuilder};
+
+    const TEST_CITIES: &[CityMetadata] = &[
+        CityMetadata {
+            id: "berlin",
+            name: "Berlin",
+            country: "germany",
+        },
+        CityMetadata {
+            id: "hamburg",
+            name: "Hamburg",
+            country: "germany",
+        },
+        CityMetadata {
+            id: "praha",
+            name: "Praha",
+            country: "czech",
+        },
+    ];
+
+    #[test]
+    fn reads_discovery_from_economy() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "economy",
+                "_nameless.1",
+                vec![
+                    ("visited_cities", tokens(&["berlin", "praha"])),
+                    ("unlocked_dealers", tokens(&["scania_berlin"])),
+                    ("unlocked_recruitments", tokens(&[])),
+                ],
+            )
+            .build();
+
+        let discovery = Discovery::from_bsii(&bsii).unwrap();
+
+        assert_eq!(discovery.visited_cities, ["berlin", "praha"]);
+        assert_eq!(discovery.unlocked_dealers, ["scania_berlin"]);
+        assert!(discovery.discovered_viewpoints.is_empty());
+        assert!(discovery.photo_trophies.is_empty());
+        assert_eq!(
+            Discovery::from_bsii(&SaveBuilder::new().build()),
+            Err(EconomyError::MissingEconomy)
+        );
+    }
+
+    #[test]
+    fn counts_completion_by_country() {
+        let visited = ["berlin", "praha", "modded_city"].map(ToString::to_string);
+
+        assert_eq!(
+            completion(&visited, TEST_CITIES.iter()),
+            Completion {
+                visited: 2,
+                total: 3
+            }
+        );
+        assert_eq!(
+            completion_by_country(&visited, TEST_CITIES),
+            BTreeMap::from([
+                (
+                    "czech",
+                    Completion {
+                        visited: 1,
+                        total: 1
+                    }
+                ),
+                (
+                    "germany",
+                    Completion {
+                        visited: 1,
+                        total: 2
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    #[ignore = "requires city metadata generated from the game defs"]
+    fn generated_cities_cover_the_base_map() {
+        assert!(!CITIES.is_empty());
+        assert!(CITIES
+            .iter()
+            .any(|city| city.id == "berlin" && city.country == "germany"));
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashSet;
+
+use crate::bsii_file::{BsiiFile, DataBlock};
+use crate::ets2::fields::{integer, string, Units};
+use crate::ets2::ProfitLogEntry;
+
+/// A hired driver, read from a `driver_ai` unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Driver {
+    pub id: String,
+    pub hometown: Option<String>,
+    pub current_city: Option<String>,
+    pub experience_points: i64,
+    pub skills: DriverSkills,
+    pub assigned_truck: Option<String>,
+    pub assigned_trailer: Option<String>,
+    /// The job the driver is on, missing while the driver rests.
+    pub current_job: Option<DriverJob>,
+    /// The daily statistics of the driver, oldest first.
+    pub profit_log: Vec<ProfitLogEntry>,
+}
+
+/// The skill levels of a driver, from 0 to 6 except for `adr`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriverSkills {
+    /// The unlocked ADR classes as a bit mask, one bit per class.
+    pub adr: i64,
+    pub long_distance: i64,
+    pub high_value: i64,
+    pub fragile: i64,
+    pub urgent: i64,
+    /// Ecodriving in the game UI.
+    pub mechanical: i64,
+}
+
+/// The job of a driver, read from a `driver_job` unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverJob {
+    pub cargo: Option<String>,
+    pub source_company: Option<String>,
+    pub target_company: Option<String>,
+}
+
+impl Driver {
+    /// Read the drivers hired in any garage.
+    pub fn all_from_bsii(bsii: &BsiiFile<'_>) -> Vec<Self> {
+        Self::all_from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn all_from_units(units: &Units<'_, '_>) -> Vec<Self> {
+        let bsii = units.bsii;
+        let mut seen = HashSet::new();
+        bsii.blocks_by_prototype_name("garage")
+            .flat_map(|garage| units.references(garage, "drivers"))
+            .filter(|block| {
+                block
+                    .prototype(bsii)
+                    .is_some_and(|prototype| prototype.name == "driver_ai")
+            })
+            .filter(|block| seen.insert(&block.id))
+            .map(|block| Self::from_block(units, block))
+            .collect()
+    }
+
+    fn from_block(units: &Units<'_, '_>, block: &DataBlock<'_>) -> Self {
+        let bsii = units.bsii;
+        let skill = |name| integer(bsii, block, name).unwrap_or(0);
+        Self {
+            id: block.id.to_string(),
+            hometown: string(bsii, block, "hometown"),
+            current_city: string(bsii, block, "current_city"),
+            experience_points: integer(bsii, block, "experience_points").unwrap_or(0),
+            skills: DriverSkills {
+                adr: skill("adr"),
+                long_distance: skill("long_dist"),
+                high_value: skill("heavy"),
+                fragile: skill("fragile"),
+                urgent: skill("urgent"),
+                mechanical: skill("mechanical"),
+            },
+            assigned_truck: string(bsii, block, "assigned_truck"),
+            assigned_trailer: string(bsii, block, "assigned_trailer"),
+            current_job: units.reference(block, "driver_job").map(|job| DriverJob {
+                cargo: string(bsii, job, "cargo"),
+                source_company: string(bsii, job, "source_company"),
+                target_company: string(bsii, job, "target_company"),
+            }),
+            profit_log: ProfitLogEntry::all_of(units, block),
+        }
+    }
+
+    pub fn revenue(&self) -> i64 {
+        self.profit_log.iter().map(|entry| entry.revenue).sum()
+    }
+
+    pub fn profit(&self) -> i64 {
+        self.profit_log.iter().map(ProfitLogEntry::profit).sum()
+    }
+
+    pub fn distance(&self) -> i64 {
+        self.profit_log.iter().map(|entry| entry.distance).sum()
+    }
+
+    /// The drivers by profit, most profitable first.
+    pub fn leaderboard(drivers: &[Driver]) -> Vec<&Driver> {
+        let mut leaderboard = drivers.iter().collect::<Vec<_>>();
+        leaderboard.sort_by(|a, b| b.profit().cmp(&a.profit()).then_with(|| a.id.cmp(&b.id)));
+        leaderboard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{profit_log_entry, token, unit_id, unit_ids, SaveBuilder};
+
+    fn driver(
+        adr: u32,
+        driver_job: &str,
+        profit_log: &str,
+    ) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("adr", DataValue::UInt32(adr)),
+            ("long_dist", DataValue::UInt32(2)),
+            ("heavy", DataValue::UInt32(3)),
+            ("fragile", DataValue::UInt32(0)),
+            ("urgent", DataValue::UInt32(1)),
+            ("mechanical", DataValue::UInt32(6)),
+            ("hometown", token("berlin")),
+            ("current_city", token("praha")),
+            ("driver_job", DataValue::Id(unit_id(driver_job))),
+            ("experience_points", DataValue::UInt32(4_200)),
+            ("assigned_truck", DataValue::Id(unit_id("_nameless.100"))),
+            ("assigned_trailer", DataValue::Id(unit_id("null"))),
+            ("profit_log", DataValue::Id(unit_id(profit_log))),
+        ]
+    }
+
+    fn sample_drivers() -> Vec<Driver> {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "garage",
+                "garage.berlin",
+                vec![("drivers", unit_ids(&["driver.anna", "null", "driver.otto"]))],
+            )
+            .unit(
+                "garage",
+                "garage.praha",
+                vec![("drivers", unit_ids(&["driver.anna"]))],
+            )
+            .unit(
+                "driver_ai",
+                "driver.anna",
+                driver(4, "_nameless.700", "_nameless.800"),
+            )
+            .unit(
+                "driver_ai",
+                "driver.otto",
+                driver(0, "null", "_nameless.900"),
+            )
+            .unit("driver_ai", "driver.idle", driver(0, "null", "null"))
+            .unit(
+                "driver_job",
+                "_nameless.700",
+                vec![
+                    ("cargo", DataValue::Id(unit_id("cargo.apples"))),
+                    ("source_company", token("tesco.berlin")),
+                    ("target_company", token("kaarfor.praha")),
+                ],
+            )
+            .unit(
+                "profit_log",
+                "_nameless.800",
+                vec![("stats_data", unit_ids(&["_nameless.801"]))],
+            )
+            .unit(
+                "profit_log",
+                "_nameless.900",
+                vec![("stats_data", unit_ids(&["_nameless.901", "_nameless.902"]))],
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.801",
+                profit_log_entry(2_000, 500, 1),
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.901",
+                profit_log_entry(1_500, 500, 1),
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.902",
+                profit_log_entry(1_000, 500, 2),
+            )
+            .build();
+        Driver::all_from_bsii(&bsii)
+    }
+
+    #[test]
+    fn reads_hired_drivers() {
+        let drivers = sample_drivers();
+
+        assert_eq!(drivers.len(), 2);
+        let anna = &drivers[0];
+        assert_eq!(anna.id, "driver.anna");
+        assert_eq!(anna.hometown.as_deref(), Some("berlin"));
+        assert_eq!(anna.current_city.as_deref(), Some("praha"));
+        assert_eq!(anna.experience_points, 4_200);
+        assert_eq!(
+            anna.skills,
+            DriverSkills {
+                adr: 4,
+                long_distance: 2,
+                high_value: 3,
+                fragile: 0,
+                urgent: 1,
+                mechanical: 6,
+            }
+        );
+        assert_eq!(anna.assigned_truck.as_deref(), Some("_nameless.100"));
+        assert_eq!(anna.assigned_trailer, None);
+        assert_eq!(
+            anna.current_job,
+            Some(DriverJob {
+                cargo: Some("cargo.apples".to_string()),
+                source_company: Some("tesco.berlin".to_string()),
+                target_company: Some("kaarfor.praha".to_string()),
+            })
+        );
+        assert_eq!(anna.revenue(), 2_000);
+        assert_eq!(anna.profit(), 1_200);
+        assert_eq!(drivers[1].current_job, None);
+    }
+
+    #[test]
+    fn ranks_drivers_by_profit() {
+        let drivers = sample_drivers();
+
+        let leaderboard = Driver::leaderboard(&drivers)
+            .into_iter()
+            .map(|driver| (driver.id.as_str(), driver.profit()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(leaderboard, [("driver.anna", 1_200), ("driver.otto", 900)]);
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use crate::bsii_file::BsiiFile;
+use crate::ets2::fields::{integer, integers, string, strings};
+use crate::ets2::PlayerSkills;
+
+/// Counters of the `economy` unit, read into [`Economy::counters`] when the
+/// game version writes them.
+const COUNTERS: &[&str] = &[
+    "driving_time",
+    "sleeping_count",
+    "free_roam_distance",
+    "discovery_distance",
+    "total_fuel_litres",
+    "total_fuel_price",
+    "total_screenshot_count",
+    "emergency_call_count",
+    "ai_crash_count",
+    "truck_color_change_count",
+    "red_light_fine_count",
+    "cancelled_job_count",
+    "achieved_feats",
+];
+
+/// The state of the game world, read from the `economy` unit of `game.sii`.
+///
+/// Most other units of a save are reachable from the references held here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Economy {
+    /// The in-game time in minutes since the start of the game.
+    pub game_time: i64,
+    pub bank: Option<String>,
+    pub player: Option<String>,
+    pub companies: Vec<String>,
+    pub garages: Vec<String>,
+    /// The visited cities, e.g. `berlin`.
+    pub visited_cities: Vec<String>,
+    /// How often each of `visited_cities` was visited.
+    pub visited_cities_count: Vec<i64>,
+    pub unlocked_dealers: Vec<String>,
+    pub unlocked_recruitments: Vec<String>,
+    /// Only written by newer game versions, empty otherwise.
+    pub discovered_viewpoints: Vec<String>,
+    /// Only written by newer game versions, empty otherwise.
+    pub photo_trophies: Vec<String>,
+    pub experience_points: i64,
+    pub skills: PlayerSkills,
+    /// The counters of [`COUNTERS`] written in the save, by field name.
+    pub counters: BTreeMap<String, i64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EconomyError {
+    MissingEconomy,
+}
+
+impl std::fmt::Display for EconomyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EconomyError::MissingEconomy => write!(f, "missing economy block"),
+        }
+    }
+}
+
+impl std::error::Error for EconomyError {}
+
+impl Economy {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, EconomyError> {
+        let block = bsii
+            .blocks_by_prototype_name("economy")
+            .next()
+            .ok_or(EconomyError::MissingEconomy)?;
+        let skill = |name| integer(bsii, block, name).unwrap_or(0);
+        Ok(Self {
+            game_time: integer(bsii, block, "game_time").unwrap_or(0),
+            bank: string(bsii, block, "bank"),
+            player: string(bsii, block, "player"),
+            companies: strings(bsii, block, "companies"),
+            garages: strings(bsii, block, "garages"),
+            visited_cities: strings(bsii, block, "visited_cities"),
+            visited_cities_count: integers(bsii, block, "visited_cities_count"),
+            unlocked_dealers: strings(bsii, block, "unlocked_dealers"),
+            unlocked_recruitments: strings(bsii, block, "unlocked_recruitments"),
+            discovered_viewpoints: strings(bsii, block, "discovered_viewpoints"),
+            photo_trophies: strings(bsii, block, "photo_trophies"),
+            experience_points: integer(bsii, block, "experience_points").unwrap_or(0),
+            skills: PlayerSkills {
+                adr: skill("adr"),
+                long_distance: skill("long_dist"),
+                high_value: skill("heavy"),
+                fragile: skill("fragile"),
+                urgent: skill("urgent"),
+                mechanical: skill("mechanical"),
+            },
+            counters: COUNTERS
+                .iter()
+                .filter_map(|name| Some((name.to_string(), integer(bsii, block, name)?)))
+                .collect(),
+        })
+    }
+
+    /// The in-game day, starting at 1.
+    pub fn game_day(&self) -> i64 {
+        self.game_time / (24 * 60) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{token, tokens, unit_id, unit_ids, SaveBuilder};
+
+    #[test]
+    fn reads_economy() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "economy",
+                "_nameless.1",
+                vec![
+                    ("bank", DataValue::Id(unit_id("bank.player"))),
+                    ("player", DataValue::Id(unit_id("player"))),
+                    ("companies", unit_ids(&["company.volatile.tesco.berlin"])),
+                    ("garages", unit_ids(&["garage.berlin", "garage.praha"])),
+                    ("game_time", DataValue::UInt32(3 * 24 * 60 + 90)),
+                    ("visited_cities", tokens(&["berlin", "praha"])),
+                    ("visited_cities_count", DataValue::UInt32Array(vec![4, 1])),
+                    ("unlocked_dealers", tokens(&["scania_berlin"])),
+                    ("unlocked_recruitments", tokens(&[])),
+                    ("discovered_viewpoints", tokens(&["viewpoint.berlin_tower"])),
+                    ("experience_points", DataValue::UInt32(12_500)),
+                    ("adr", DataValue::UInt32(0b11)),
+                    ("long_dist", DataValue::UInt32(2)),
+                    ("driving_time", DataValue::UInt32(5400)),
+                    ("red_light_fine_count", DataValue::UInt32(2)),
+                    ("stored_gps_behind_waypoints", token("foo")),
+                ],
+            )
+            .build();
+
+        let economy = Economy::from_bsii(&bsii).unwrap();
+
+        assert_eq!(economy.game_day(), 4);
+        assert_eq!(economy.bank.as_deref(), Some("bank.player"));
+        assert_eq!(economy.player.as_deref(), Some("player"));
+        assert_eq!(economy.garages, ["garage.berlin", "garage.praha"]);
+        assert_eq!(economy.visited_cities, ["berlin", "praha"]);
+        assert_eq!(economy.visited_cities_count, [4, 1]);
+        assert_eq!(economy.unlocked_dealers, ["scania_berlin"]);
+        assert!(economy.unlocked_recruitments.is_empty());
+        assert_eq!(economy.discovered_viewpoints, ["viewpoint.berlin_tower"]);
+        assert!(economy.photo_trophies.is_empty());
+        assert_eq!(economy.experience_points, 12_500);
+        assert_eq!(
+            economy.skills,
+            PlayerSkills {
+                adr: 0b11,
+                long_distance: 2,
+                ..PlayerSkills::default()
+            }
+        );
+        assert_eq!(
+            economy.counters,
+            BTreeMap::from([
+                ("driving_time".to_string(), 5400),
+                ("red_light_fine_count".to_string(), 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn requires_economy() {
+        assert_eq!(
+            Economy::from_bsii(&SaveBuilder::new().build()),
+            Err(EconomyError::MissingEconomy)
+        );
+    }
+}
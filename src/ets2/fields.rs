@@ -0,0 +1,133 @@
+//! Helpers to read typed values from the fields of a unit.
+//!
+//! Field types differ between game versions (e.g. `uint32` vs `int64` money),
+//! so the helpers accept every type that can represent the requested value.
+//! Unsigned `nil` values and null IDs are treated as missing.
+
+use std::collections::HashMap;
+
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id};
+
+/// Resolves the references between units through an index of all units by ID.
+pub(crate) struct Units<'data, 'a> {
+    pub(crate) bsii: &'data BsiiFile<'a>,
+    by_id: HashMap<&'data Id, &'data DataBlock<'a>>,
+}
+
+impl<'data, 'a> Units<'data, 'a> {
+    pub(crate) fn new(bsii: &'data BsiiFile<'a>) -> Self {
+        Self {
+            bsii,
+            by_id: bsii.data_blocks().map(|block| (&block.id, block)).collect(),
+        }
+    }
+
+    /// The unit referenced by an ID field, or `None` for null and dangling
+    /// references.
+    pub(crate) fn reference(
+        &self,
+        block: &DataBlock<'a>,
+        name: &str,
+    ) -> Option<&'data DataBlock<'a>> {
+        match block.field(self.bsii, name)? {
+            DataValue::Id(id) => self.by_id.get(id).copied(),
+            _ => None,
+        }
+    }
+
+    /// The units referenced by an ID array field, skipping null and dangling
+    /// references.
+    pub(crate) fn references(
+        &self,
+        block: &DataBlock<'a>,
+        name: &str,
+    ) -> Vec<&'data DataBlock<'a>> {
+        match block.field(self.bsii, name) {
+            Some(DataValue::IdArray(ids)) => ids
+                .iter()
+                .filter_map(|id| self.by_id.get(id).copied())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+pub(crate) fn string(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Option<String> {
+    match block.field(bsii, name)? {
+        DataValue::String(value) => Some(value.to_string()),
+        DataValue::EncodedString(value) => Some(value.clone()),
+        DataValue::Id(id) if !is_null(id) => Some(id.to_string()),
+        DataValue::Enum(value) => {
+            let prototype = block.prototype(bsii)?.field(name)?;
+            prototype
+                .enum_values
+                .as_ref()?
+                .get(value)
+                .map(|name| name.to_string())
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn strings(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Vec<String> {
+    match block.field(bsii, name) {
+        Some(DataValue::StringArray(values)) => values.iter().map(ToString::to_string).collect(),
+        Some(DataValue::EncodedStringArray(values)) => values.clone(),
+        Some(DataValue::IdArray(ids)) => ids
+            .iter()
+            .filter(|id| !is_null(id))
+            .map(ToString::to_string)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub(crate) fn integer(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Option<i64> {
+    match block.field(bsii, name)? {
+        DataValue::Int32(value) => Some(i64::from(*value)),
+        DataValue::Int64(value) => Some(*value),
+        DataValue::UInt16(value) if *value != u16::MAX => Some(i64::from(*value)),
+        DataValue::UInt32(value) if *value != u32::MAX => Some(i64::from(*value)),
+        DataValue::UInt64(value) if *value != u64::MAX => i64::try_from(*value).ok(),
+        DataValue::Enum(value) => Some(i64::from(*value)),
+        _ => None,
+    }
+}
+
+pub(crate) fn integers(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Vec<i64> {
+    match block.field(bsii, name) {
+        Some(DataValue::Int32Array(values)) => {
+            values.iter().map(|value| i64::from(*value)).collect()
+        }
+        Some(DataValue::Int64Array(values)) => values.clone(),
+        Some(DataValue::UInt16Array(values)) => {
+            values.iter().map(|value| i64::from(*value)).collect()
+        }
+        Some(DataValue::UInt32Array(values)) => {
+            values.iter().map(|value| i64::from(*value)).collect()
+        }
+        Some(DataValue::UInt64Array(values)) => values
+            .iter()
+            .filter_map(|value| i64::try_from(*value).ok())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub(crate) fn float(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Option<f64> {
+    match block.field(bsii, name)? {
+        DataValue::Float(value) => Some(f64::from(*value)),
+        _ => integer(bsii, block, name).map(|value| value as f64),
+    }
+}
+
+pub(crate) fn boolean(bsii: &BsiiFile<'_>, block: &DataBlock<'_>, name: &str) -> Option<bool> {
+    match block.field(bsii, name)? {
+        DataValue::Bool(value) => Some(*value),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_null(id: &Id) -> bool {
+    matches!(id, Id::Named(parts) if parts.is_empty())
+}
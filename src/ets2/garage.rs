@@ -0,0 +1,236 @@
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue};
+use crate::ets2::fields::{integer, strings, Units};
+
+/// A garage of the map, read from a `garage` unit and its `profit_log`.
+///
+/// Every city with a garage has a unit, owned by the player or not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garage {
+    pub id: String,
+    /// The city of the garage, e.g. `berlin` for `garage.berlin`.
+    pub city: String,
+    /// The size of the garage, 0 when it is not owned.
+    pub status: i64,
+    /// The number of vehicle slots, including empty ones.
+    pub slot_count: usize,
+    pub vehicles: Vec<String>,
+    pub drivers: Vec<String>,
+    pub trailers: Vec<String>,
+    pub productivity: Option<i64>,
+    /// The daily statistics of the garage, oldest first.
+    pub profit_log: Vec<ProfitLogEntry>,
+}
+
+/// A day of the `profit_log` of a garage or driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfitLogEntry {
+    pub revenue: i64,
+    pub wage: i64,
+    pub maintenance: i64,
+    pub fuel: i64,
+    pub distance: i64,
+    pub cargo_count: i64,
+    /// The in-game day of the entry.
+    pub timestamp_day: Option<i64>,
+}
+
+impl Garage {
+    pub fn all_from_bsii(bsii: &BsiiFile<'_>) -> Vec<Self> {
+        Self::all_from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn all_from_units(units: &Units<'_, '_>) -> Vec<Self> {
+        let bsii = units.bsii;
+        bsii.blocks_by_prototype_name("garage")
+            .map(|block| Self::from_block(units, block))
+            .collect()
+    }
+
+    fn from_block(units: &Units<'_, '_>, block: &DataBlock<'_>) -> Self {
+        let bsii = units.bsii;
+        let id = block.id.to_string();
+        Self {
+            city: id.rsplit('.').next().unwrap_or_default().to_string(),
+            id,
+            status: integer(bsii, block, "status").unwrap_or(0),
+            slot_count: match block.field(bsii, "vehicles") {
+                Some(DataValue::IdArray(vehicles)) => vehicles.len(),
+                _ => 0,
+            },
+            vehicles: strings(bsii, block, "vehicles"),
+            drivers: strings(bsii, block, "drivers"),
+            trailers: strings(bsii, block, "trailers"),
+            productivity: integer(bsii, block, "productivity"),
+            profit_log: ProfitLogEntry::all_of(units, block),
+        }
+    }
+
+    pub fn is_owned(&self) -> bool {
+        self.status != 0
+    }
+
+    pub fn revenue(&self) -> i64 {
+        self.profit_log.iter().map(|entry| entry.revenue).sum()
+    }
+
+    /// The wages, maintenance and fuel paid in the profit log.
+    pub fn expenses(&self) -> i64 {
+        self.profit_log.iter().map(ProfitLogEntry::expenses).sum()
+    }
+
+    pub fn profit(&self) -> i64 {
+        self.revenue() - self.expenses()
+    }
+
+    pub fn distance(&self) -> i64 {
+        self.profit_log.iter().map(|entry| entry.distance).sum()
+    }
+
+    /// The owned garages by profit, most profitable first.
+    pub fn rank_by_earnings(garages: &[Garage]) -> Vec<&Garage> {
+        let mut ranking = garages
+            .iter()
+            .filter(|garage| garage.is_owned())
+            .collect::<Vec<_>>();
+        ranking.sort_by(|a, b| b.profit().cmp(&a.profit()).then_with(|| a.id.cmp(&b.id)));
+        ranking
+    }
+}
+
+impl ProfitLogEntry {
+    /// The entries of the `profit_log` referenced by a garage or driver.
+    pub(crate) fn all_of(units: &Units<'_, '_>, block: &DataBlock<'_>) -> Vec<Self> {
+        units
+            .reference(block, "profit_log")
+            .map(|log| {
+                units
+                    .references(log, "stats_data")
+                    .into_iter()
+                    .map(|entry| Self::from_block(units.bsii, entry))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn from_block(bsii: &BsiiFile<'_>, block: &DataBlock<'_>) -> Self {
+        Self {
+            revenue: integer(bsii, block, "revenue").unwrap_or(0),
+            wage: integer(bsii, block, "wage").unwrap_or(0),
+            maintenance: integer(bsii, block, "maintenance").unwrap_or(0),
+            fuel: integer(bsii, block, "fuel").unwrap_or(0),
+            distance: integer(bsii, block, "distance").unwrap_or(0),
+            cargo_count: integer(bsii, block, "cargo_count").unwrap_or(0),
+            timestamp_day: integer(bsii, block, "timestamp_day"),
+        }
+    }
+
+    pub fn expenses(&self) -> i64 {
+        self.wage + self.maintenance + self.fuel
+    }
+
+    pub fn profit(&self) -> i64 {
+        self.revenue - self.expenses()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ets2::test_support::{profit_log_entry, unit_id, unit_ids, SaveBuilder};
+
+    fn garage(
+        status: u32,
+        vehicles: &[&str],
+        drivers: &[&str],
+        profit_log: &str,
+    ) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("vehicles", unit_ids(vehicles)),
+            ("drivers", unit_ids(drivers)),
+            ("trailers", unit_ids(&[])),
+            ("status", DataValue::UInt32(status)),
+            ("profit_log", DataValue::Id(unit_id(profit_log))),
+            ("productivity", DataValue::UInt32(0)),
+        ]
+    }
+
+    fn sample_garages() -> Vec<Garage> {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "garage",
+                "garage.berlin",
+                garage(
+                    2,
+                    &["_nameless.100", "null", "null"],
+                    &["driver.hans", "null", "null"],
+                    "_nameless.500",
+                ),
+            )
+            .unit(
+                "garage",
+                "garage.praha",
+                garage(1, &["_nameless.200"], &["null"], "_nameless.600"),
+            )
+            .unit("garage", "garage.wien", garage(0, &[], &[], "null"))
+            .unit(
+                "profit_log",
+                "_nameless.500",
+                vec![("stats_data", unit_ids(&["_nameless.501", "_nameless.502"]))],
+            )
+            .unit(
+                "profit_log",
+                "_nameless.600",
+                vec![("stats_data", unit_ids(&["_nameless.601"]))],
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.501",
+                profit_log_entry(3_000, 600, 10),
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.502",
+                profit_log_entry(2_000, 400, 11),
+            )
+            .unit(
+                "profit_log_entry",
+                "_nameless.601",
+                profit_log_entry(9_000, 1_000, 11),
+            )
+            .build();
+        Garage::all_from_bsii(&bsii)
+    }
+
+    #[test]
+    fn reads_garages_with_profit_log() {
+        let garages = sample_garages();
+
+        assert_eq!(garages.len(), 3);
+        let berlin = &garages[0];
+        assert_eq!(berlin.city, "berlin");
+        assert_eq!(berlin.status, 2);
+        assert_eq!(berlin.slot_count, 3);
+        assert_eq!(berlin.vehicles, ["_nameless.100"]);
+        assert_eq!(berlin.drivers, ["driver.hans"]);
+        assert_eq!(berlin.profit_log.len(), 2);
+        assert_eq!(berlin.profit_log[1].timestamp_day, Some(11));
+        assert_eq!(berlin.revenue(), 5_000);
+        assert_eq!(berlin.expenses(), 1_600);
+        assert_eq!(berlin.profit(), 3_400);
+        assert_eq!(berlin.distance(), 1_000);
+        assert!(!garages[2].is_owned());
+        assert!(garages[2].profit_log.is_empty());
+    }
+
+    #[test]
+    fn ranks_owned_garages_by_earnings() {
+        let garages = sample_garages();
+
+        let ranking = Garage::rank_by_earnings(&garages)
+            .into_iter()
+            .map(|garage| garage.city.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranking, ["praha", "berlin"]);
+    }
+}
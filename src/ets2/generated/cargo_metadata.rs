@@ -12,6 +12,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "acid",
@@ -21,6 +22,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "air_mails",
@@ -30,6 +32,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "aircft_tires",
@@ -39,6 +42,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "almond",
@@ -48,6 +52,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "alu_ingot",
@@ -57,6 +62,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "alu_profile",
@@ -66,6 +72,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ammonia",
@@ -75,6 +82,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["gastank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ammunition",
@@ -84,6 +92,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "apples",
@@ -93,6 +102,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "apples_c",
@@ -102,6 +112,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "arsenic",
@@ -111,6 +122,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "atl_cod_flt",
@@ -120,6 +132,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "backfl_prev",
@@ -129,6 +142,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "barley",
@@ -138,6 +152,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "basil",
@@ -147,6 +162,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "battery",
@@ -156,6 +172,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "curtainside", "dryvan", "insulated"],
         trailer_categories: &["container", "dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "beans",
@@ -165,6 +182,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "beef_meat",
@@ -174,6 +192,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "beverages",
@@ -183,6 +202,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "beverages_c",
@@ -192,6 +212,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "beverages_t",
@@ -201,6 +222,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "big_bag_seed",
@@ -210,6 +232,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan", "flatbed_brck"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "boric_acid",
@@ -219,6 +242,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "bottle_water",
@@ -228,6 +252,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "brake_fluid",
@@ -237,6 +262,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "brake_pads",
@@ -246,6 +272,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "bricks",
@@ -255,6 +282,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed_brck"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "can_sardines",
@@ -264,6 +292,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "canned_beans",
@@ -273,6 +302,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "canned_beef",
@@ -282,6 +312,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "canned_pork",
@@ -291,6 +322,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "canned_tuna",
@@ -300,6 +332,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_balt1",
@@ -309,6 +342,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_anscat1"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_balt2",
@@ -318,6 +352,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_van_anscat2"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_d",
@@ -327,6 +362,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_ansca_d"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_f",
@@ -336,6 +372,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_ansca_f"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_gr",
@@ -345,6 +382,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_anscagr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_ibe",
@@ -354,6 +392,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_anscabe"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "car_it",
@@ -363,6 +402,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_anscait"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "caravans",
@@ -372,6 +412,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_trucanscans"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "carb_water",
@@ -381,6 +422,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "carbn_pwdr_c",
@@ -390,6 +432,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "carrots",
@@ -399,6 +442,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "carrots_c",
@@ -408,6 +452,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cars_fr",
@@ -417,6 +462,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_anscafr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cauliflower",
@@ -426,6 +472,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "caviar",
@@ -435,6 +482,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cement",
@@ -444,6 +492,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cheese",
@@ -453,6 +502,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chem_sorb_c",
@@ -462,6 +512,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chem_sorbent",
@@ -471,6 +522,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chemicals",
@@ -480,6 +532,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chewing_gums",
@@ -489,6 +542,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chicken_meat",
@@ -498,6 +552,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chimney_syst",
@@ -507,6 +562,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chlorine",
@@ -516,6 +572,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chlorine_t",
@@ -525,6 +582,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["gastank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "chocolate",
@@ -534,6 +592,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "clothes",
@@ -543,6 +602,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "clothes_c",
@@ -552,6 +612,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "coal",
@@ -561,6 +622,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "coconut_milk",
@@ -570,6 +632,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "coconut_oil",
@@ -579,6 +642,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "comp_process",
@@ -588,6 +652,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "conc_juice_t",
@@ -597,6 +662,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "concen_juice",
@@ -606,6 +672,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "concr_beams2",
@@ -615,6 +682,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "concr_cent",
@@ -624,6 +692,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "concr_stair",
@@ -633,6 +702,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cont_trees",
@@ -642,6 +712,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "contamin",
@@ -651,6 +722,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "copp_rf_gutt",
@@ -660,6 +732,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "corks",
@@ -669,6 +742,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cott_cheese",
@@ -678,6 +752,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cott_harvest",
@@ -687,6 +762,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.65"),
         body_types: &["lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "crawler",
@@ -696,6 +772,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cut_flowers",
@@ -705,6 +782,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "cyanide",
@@ -714,6 +792,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "czl_es300",
@@ -723,6 +802,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "czl_muv75",
@@ -732,6 +812,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "desinfection",
@@ -741,6 +822,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "diesel",
@@ -750,6 +832,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["fueltank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "diesel_gen",
@@ -759,6 +842,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "digger1000",
@@ -768,6 +852,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "digger500",
@@ -777,6 +862,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "dryers",
@@ -786,6 +872,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.75"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "drymilk",
@@ -795,6 +882,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "dynamite",
@@ -804,6 +892,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "elect_wiring",
@@ -813,6 +902,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "electronics",
@@ -822,6 +912,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.8"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "emp_wine_bar",
@@ -831,6 +922,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "emp_wine_bot",
@@ -840,6 +932,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.71"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "empty_barr",
@@ -849,6 +942,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "empty_palet",
@@ -858,6 +952,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "empty_spool",
@@ -867,6 +962,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "emptytank",
@@ -876,6 +972,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ethane",
@@ -885,6 +982,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["gastank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "excav_soil",
@@ -894,6 +992,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "excavator",
@@ -903,6 +1002,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "exhausts_c",
@@ -912,6 +1012,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.68"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "explosives",
@@ -921,6 +1022,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fertilizer",
@@ -930,6 +1032,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan", "flatbed_brck"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fireworks",
@@ -939,6 +1042,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fish_chips",
@@ -948,6 +1052,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "floorpanels",
@@ -957,6 +1062,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["_paneoorflls"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "flour",
@@ -966,6 +1072,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fluorine",
@@ -975,6 +1082,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "food_oil_t",
@@ -984,6 +1092,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "forklifts",
@@ -993,6 +1102,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fresh_fish",
@@ -1002,6 +1112,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "froz_octopi",
@@ -1011,6 +1122,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "frozen_hake",
@@ -1020,6 +1132,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "frsh_herbs",
@@ -1029,6 +1142,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fuel_oil",
@@ -1038,6 +1152,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["fueltank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fuel_tanks",
@@ -1047,6 +1162,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "fueltanker",
@@ -1056,6 +1172,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "furniture",
@@ -1065,6 +1182,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.71"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "garlic",
@@ -1074,6 +1192,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "glass",
@@ -1083,6 +1202,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["_paneassglss"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "glass_packed",
@@ -1092,6 +1212,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["_gls_lergled"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "gnocchi",
@@ -1101,6 +1222,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "goat_cheese",
@@ -1110,6 +1232,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "granite_cube",
@@ -1119,6 +1242,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "grapes",
@@ -1128,6 +1252,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.55"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "graph_grease",
@@ -1137,6 +1262,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "grass_rolls",
@@ -1146,6 +1272,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "gravel",
@@ -1155,6 +1282,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "guard_rails",
@@ -1164,6 +1292,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "gummy_bears",
@@ -1173,6 +1302,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "harvest_bins",
@@ -1182,6 +1312,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hchemicals",
@@ -1191,6 +1322,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.65"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hi_volt_cabl",
@@ -1200,6 +1332,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hipresstank",
@@ -1209,6 +1342,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hmetal",
@@ -1218,6 +1352,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "honey",
@@ -1227,6 +1362,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "horse_tr",
@@ -1236,6 +1372,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.65"),
         body_types: &["_trucanshotr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hvac",
@@ -1245,6 +1382,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.85"),
         body_types: &["lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hwaste",
@@ -1254,6 +1392,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hydrochlor",
@@ -1263,6 +1402,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "hydrogen",
@@ -1272,6 +1412,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ibc_cont",
@@ -1281,6 +1422,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "icecream",
@@ -1290,6 +1432,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["refrigerated"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "iced_coffee",
@@ -1299,6 +1442,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "iron_pipes",
@@ -1308,6 +1452,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["log"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "kerosene",
@@ -1317,6 +1462,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["fueltank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ketchup",
@@ -1326,6 +1472,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lamb_stom",
@@ -1335,6 +1482,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "large_cont",
@@ -1344,6 +1492,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["flatbed", "lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "largetubes",
@@ -1353,6 +1502,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont", "log"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lavender",
@@ -1362,6 +1512,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lead",
@@ -1371,6 +1522,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "limonades",
@@ -1380,6 +1532,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "live_cattle",
@@ -1389,6 +1542,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["livestock"],
         trailer_categories: &["livestock"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "live_pigs",
@@ -1398,6 +1552,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["livestock"],
         trailer_categories: &["livestock"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "liver_paste",
@@ -1407,6 +1562,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "logs",
@@ -1416,6 +1572,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["log"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lpg",
@@ -1425,6 +1582,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lpg_t",
@@ -1434,6 +1592,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["gastank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lumber",
@@ -1443,6 +1602,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lumber_p",
@@ -1452,6 +1612,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "lux_yacht",
@@ -1461,6 +1622,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.99"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "magnesium",
@@ -1470,6 +1632,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "maple_syrup",
@@ -1479,6 +1642,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "marb_blck",
@@ -1488,6 +1652,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "marb_blck2",
@@ -1497,6 +1662,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "marb_slab",
@@ -1506,6 +1672,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mason_jars",
@@ -1515,6 +1682,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mat_handler",
@@ -1524,6 +1692,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "med_equip",
@@ -1533,6 +1702,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.8"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "med_vaccine",
@@ -1542,6 +1712,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mercuric",
@@ -1551,6 +1722,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "metal_beams",
@@ -1560,6 +1732,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "metal_cans",
@@ -1569,6 +1742,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "metal_pipes",
@@ -1578,6 +1752,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "milk",
@@ -1587,6 +1762,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "milk_t",
@@ -1596,6 +1772,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mondeos",
@@ -1605,6 +1782,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_ansmoos"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "moto_tires",
@@ -1614,6 +1792,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "curtainside", "dryvan", "flatbed_cont"],
         trailer_categories: &["container", "dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "motor_oil",
@@ -1623,6 +1802,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "motor_oil_c",
@@ -1632,6 +1812,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "motorcycles",
@@ -1641,6 +1822,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mozzarela",
@@ -1650,6 +1832,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "mtl_coil",
@@ -1659,6 +1842,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "natur_rubber",
@@ -1668,6 +1852,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "neon",
@@ -1677,6 +1862,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "nitrocel",
@@ -1686,6 +1872,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "nitrogen",
@@ -1695,6 +1882,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "nonalco_beer",
@@ -1704,6 +1892,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "nuts",
@@ -1713,6 +1902,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "nylon_cord",
@@ -1722,6 +1912,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "oil",
@@ -1731,6 +1922,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["fueltank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "oil_filt_c",
@@ -1740,6 +1932,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "oil_filters",
@@ -1749,6 +1942,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "olive_oil",
@@ -1758,6 +1952,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "olive_oil_t",
@@ -1767,6 +1962,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "olive_tree",
@@ -1776,6 +1972,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["flatbed_brck"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "olives",
@@ -1785,6 +1982,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "onion",
@@ -1794,6 +1992,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "oranges",
@@ -1803,6 +2002,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ore",
@@ -1812,6 +2012,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "outdr_flr_tl",
@@ -1821,6 +2022,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan", "flatbed_brck"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "overweight",
@@ -1830,6 +2032,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["lowbed"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "packag_food",
@@ -1839,6 +2042,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.8"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "paper",
@@ -1848,6 +2052,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pasta",
@@ -1857,6 +2062,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pears",
@@ -1866,6 +2072,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "peas",
@@ -1875,6 +2082,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "perfor_frks",
@@ -1884,6 +2092,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pesticide",
@@ -1893,6 +2102,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pesto",
@@ -1902,6 +2112,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pet_food",
@@ -1911,6 +2122,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pet_food_c",
@@ -1920,6 +2132,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "petrol",
@@ -1929,6 +2142,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["fueltank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "phosphor",
@@ -1938,6 +2152,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pickup_gr",
@@ -1947,6 +2162,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_trucanspigr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plant_substr",
@@ -1956,6 +2172,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan", "flatbed_brck"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plast_film",
@@ -1965,6 +2182,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plast_film_c",
@@ -1974,6 +2192,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plast_pipes",
@@ -1983,6 +2202,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plastic_gra",
@@ -1992,6 +2212,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plumb_suppl",
@@ -2001,6 +2222,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "plums",
@@ -2010,6 +2232,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pnut_butter",
@@ -2019,6 +2242,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "polyst_box",
@@ -2028,6 +2252,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pork_meat",
@@ -2037,6 +2262,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "post_packag",
@@ -2046,6 +2272,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pot_flowers",
@@ -2055,6 +2282,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "potahydro",
@@ -2064,6 +2292,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "potassium",
@@ -2073,6 +2302,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "potatoes",
@@ -2082,6 +2312,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "precast_strs",
@@ -2091,6 +2322,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "press_sl_val",
@@ -2100,6 +2332,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "propane",
@@ -2109,6 +2342,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["gastank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "prosciutto",
@@ -2118,6 +2352,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "protec_cloth",
@@ -2127,6 +2362,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "pumps",
@@ -2136,6 +2372,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "radiators",
@@ -2145,6 +2382,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "re_bars",
@@ -2154,6 +2392,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "refl_posts",
@@ -2163,6 +2402,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "rice",
@@ -2172,6 +2412,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "rice_c",
@@ -2181,6 +2422,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "roof_tiles",
@@ -2190,6 +2432,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan", "flatbed_brck"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "roofing_felt",
@@ -2199,6 +2442,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "rooflights",
@@ -2208,6 +2452,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "rye",
@@ -2217,6 +2462,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "salm_fillet",
@@ -2226,6 +2472,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "salt_spice_c",
@@ -2235,6 +2482,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "salt_spices",
@@ -2244,6 +2492,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sand",
@@ -2253,6 +2502,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sandwch_pnls",
@@ -2262,6 +2512,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sausages",
@@ -2271,6 +2522,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sawpanels",
@@ -2280,6 +2532,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "scaffoldings",
@@ -2289,6 +2542,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "scania_tr",
@@ -2298,6 +2552,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["_trucanssctr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "scooters",
@@ -2307,6 +2562,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "scrap_metals",
@@ -2316,6 +2572,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "seal_bearing",
@@ -2325,6 +2582,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sheep_wool",
@@ -2334,6 +2592,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "shock_absorb",
@@ -2343,6 +2602,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "silica",
@@ -2352,6 +2612,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "smokd_eel",
@@ -2361,6 +2622,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "smokd_sprats",
@@ -2370,6 +2632,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sodchlor",
@@ -2379,6 +2642,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sodhydro",
@@ -2388,6 +2652,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sodium",
@@ -2397,6 +2662,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "soy_milk",
@@ -2406,6 +2672,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "soy_milk_t",
@@ -2415,6 +2682,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["foodtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "spher_valves",
@@ -2424,6 +2692,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sq_tub",
@@ -2433,6 +2702,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "steel_cord",
@@ -2442,6 +2712,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "stone_dust",
@@ -2451,6 +2722,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "stone_wool",
@@ -2460,6 +2732,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "stones",
@@ -2469,6 +2742,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "straw_bales",
@@ -2478,6 +2752,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan", "flatbed", "flatbed_cont"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sugar",
@@ -2487,6 +2762,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sulfuric",
@@ -2496,6 +2772,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "sulfuric_t",
@@ -2505,6 +2782,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["chemtank"],
         trailer_categories: &["tr_tank"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "tableware",
@@ -2514,6 +2792,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: None,
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "ter_forklift",
@@ -2523,6 +2802,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "tomatoes",
@@ -2532,6 +2812,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "toys",
@@ -2541,6 +2822,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "tractors",
@@ -2550,6 +2832,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.69"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "train_part",
@@ -2559,6 +2842,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "train_part2",
@@ -2568,6 +2852,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "transmis",
@@ -2577,6 +2862,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "truck_batt",
@@ -2586,6 +2872,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "truck_batt_c",
@@ -2595,6 +2882,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "truck_rims",
@@ -2604,6 +2892,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "truck_rims_c",
@@ -2613,6 +2902,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "truck_tyres",
@@ -2622,6 +2912,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.15"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "tyres",
@@ -2631,6 +2922,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "used_battery",
@@ -2640,6 +2932,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "used_packag",
@@ -2649,6 +2942,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "used_plast",
@@ -2658,6 +2952,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "used_plast_c",
@@ -2667,6 +2962,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "valentine",
@@ -2676,6 +2972,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["_scs__26vane"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vans_fd",
@@ -2685,6 +2982,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_van_ansvafd"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vans_id",
@@ -2694,6 +2992,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_van_ansvaid"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vans_vt",
@@ -2703,6 +3002,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_trucansvavt"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vent_tube",
@@ -2712,6 +3012,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["flatbed", "flatbed_cont"],
         trailer_categories: &["flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vinegar",
@@ -2721,6 +3022,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "vinegar_c",
@@ -2730,6 +3032,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["container", "flatbed_cont"],
         trailer_categories: &["container", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "volvo_cars",
@@ -2739,6 +3042,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.95"),
         body_types: &["_car_ansvors"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "volvo_tr",
@@ -2748,6 +3052,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.9"),
         body_types: &["_trucansvotr"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wallpanels",
@@ -2757,6 +3062,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["_paneallwals"],
         trailer_categories: &[],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "watermelons",
@@ -2766,6 +3072,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "watertank",
@@ -2775,6 +3082,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.3"),
         body_types: &["flatbed", "flatbed_cont", "lowbed", "lowboy"],
         trailer_categories: &["flatbed", "lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wheat",
@@ -2784,6 +3092,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "windml_eng",
@@ -2793,6 +3102,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.71"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "windml_tube",
@@ -2802,6 +3112,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.4"),
         body_types: &["lowbed", "lowboy"],
         trailer_categories: &["lowbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wood_bark",
@@ -2811,6 +3122,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wooden_beams",
@@ -2820,6 +3132,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan", "flatbed", "flatbed_cont", "log"],
         trailer_categories: &["dryvan", "flatbed"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wrk_cloth",
@@ -2829,6 +3142,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.2"),
         body_types: &["curtainside", "dryvan"],
         trailer_categories: &["dryvan"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "wshavings",
@@ -2838,6 +3152,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.1"),
         body_types: &["dumper", "silo"],
         trailer_categories: &["bulk"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "yogurt",
@@ -2847,6 +3162,7 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.6"),
         body_types: &["insulated", "refrigerated"],
         trailer_categories: &["refrigerated"],
+        unit_reward_per_km: None,
     },
     CargoMetadata {
         id: "young_seed",
@@ -2856,5 +3172,6 @@ pub const CARGOS: &[CargoMetadata] = &[
         fragility: Some("0.5"),
         body_types: &["curtainside", "dryvan", "insulated", "refrigerated"],
         trailer_categories: &["dryvan", "refrigerated"],
+        unit_reward_per_km: None,
     },
 ];
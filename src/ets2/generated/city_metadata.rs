@@ -0,0 +1,6 @@
+// @generated by `cargo xtask extract-ets2-defs --defs def/def`
+// Do not edit by hand.
+
+use crate::ets2::CityMetadata;
+
+pub const CITIES: &[CityMetadata] = &[];
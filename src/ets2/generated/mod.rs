@@ -1 +1,2 @@
 pub mod cargo_metadata;
+pub mod city_metadata;
@@ -0,0 +1,322 @@
+use std::cmp::Ordering;
+
+use crate::bsii_file::{BsiiFile, DataBlock};
+use crate::ets2::fields::{integer, string, Units};
+use crate::ets2::generated::cargo_metadata::CARGOS;
+use crate::ets2::save::{cargo_id, COMPANY_PREFIX};
+use crate::ets2::CargoMetadata;
+
+/// The job offers of all companies, read from the `job_offer_data` units
+/// referenced by the `company` units.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobMarket {
+    pub offers: Vec<JobOffer>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobOffer {
+    /// The company offering the job, e.g. `tesco`.
+    pub source_company: String,
+    pub source_city: String,
+    pub target_company: String,
+    pub target_city: String,
+    /// The cargo ID without prefix, e.g. `apples`.
+    pub cargo: String,
+    pub units_count: i64,
+    /// The urgency of the job, missing for jobs without a deadline.
+    pub urgency: Option<i64>,
+    pub distance_km: i64,
+    /// The in-game time in minutes when the offer expires.
+    pub expiration_time: Option<i64>,
+    pub trailer_definition: Option<String>,
+    /// The metadata of the cargo, missing for cargos of mods.
+    pub metadata: Option<&'static CargoMetadata>,
+}
+
+/// Criteria for [`JobMarket::filter`]. Offers must match every criterion that
+/// is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JobFilter {
+    pub origin_city: Option<String>,
+    pub cargo_group: Option<String>,
+    pub adr_class: Option<String>,
+    pub trailer_category: Option<String>,
+}
+
+impl JobMarket {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Self {
+        Self::from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn from_units(units: &Units<'_, '_>) -> Self {
+        let bsii = units.bsii;
+        let offers = bsii
+            .blocks_by_prototype_name("company")
+            .flat_map(|company| {
+                units
+                    .references(company, "job_offer")
+                    .into_iter()
+                    .filter_map(move |offer| JobOffer::from_block(bsii, company, offer))
+            })
+            .collect();
+        Self { offers }
+    }
+
+    /// The offers matching the filter, in the order of the save.
+    pub fn filter(&self, filter: &JobFilter) -> Vec<&JobOffer> {
+        self.offers
+            .iter()
+            .filter(|offer| offer.matches(filter))
+            .collect()
+    }
+
+    /// The offers matching the filter by pay per km, best paid first. Offers
+    /// without a known reward come last.
+    pub fn by_pay_per_km(&self, filter: &JobFilter) -> Vec<&JobOffer> {
+        let mut offers = self.filter(filter);
+        offers.sort_by(|a, b| match (a.pay_per_km(), b.pay_per_km()) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        offers
+    }
+}
+
+impl JobOffer {
+    fn from_block(
+        bsii: &BsiiFile<'_>,
+        company: &DataBlock<'_>,
+        block: &DataBlock<'_>,
+    ) -> Option<Self> {
+        let cargo = cargo_id(&string(bsii, block, "cargo")?).to_string();
+        let (source_company, source_city) = split_company(&company.id.to_string());
+        let target = string(bsii, block, "target").unwrap_or_default();
+        let (target_company, target_city) = split_company(&target);
+        Some(Self {
+            source_company,
+            source_city,
+            target_company,
+            target_city,
+            metadata: CARGOS.iter().find(|metadata| metadata.id == cargo),
+            cargo,
+            units_count: integer(bsii, block, "units_count").unwrap_or(1),
+            urgency: integer(bsii, block, "urgency"),
+            distance_km: integer(bsii, block, "shortest_distance_km").unwrap_or(0),
+            expiration_time: integer(bsii, block, "expiration_time"),
+            trailer_definition: string(bsii, block, "trailer_definition"),
+        })
+    }
+
+    /// The base reward per km of the offer: `unit_reward_per_km` of the
+    /// cargo metadata times `units_count`.
+    ///
+    /// The distance cancels out of the reward per km, see
+    /// [`JobOffer::base_reward`]. Bonuses for urgency, the player's skills and
+    /// the cargo risk are not included, as the economy data defining them is
+    /// not extracted.
+    pub fn pay_per_km(&self) -> Option<f64> {
+        let unit_reward = self.metadata?.unit_reward_per_km?.parse::<f64>().ok()?;
+        Some(unit_reward * self.units_count as f64)
+    }
+
+    /// The base reward of the whole job: [`JobOffer::pay_per_km`] times
+    /// `distance_km`.
+    pub fn base_reward(&self) -> Option<f64> {
+        Some(self.pay_per_km()? * self.distance_km as f64)
+    }
+
+    pub fn matches(&self, filter: &JobFilter) -> bool {
+        let metadata = self.metadata;
+        let matches_metadata =
+            |criterion: &Option<String>, values: fn(&CargoMetadata) -> &[&str]| {
+                criterion.as_deref().is_none_or(|criterion| {
+                    metadata.is_some_and(|metadata| values(metadata).contains(&criterion))
+                })
+            };
+        filter
+            .origin_city
+            .as_deref()
+            .is_none_or(|city| self.source_city == city)
+            && matches_metadata(&filter.cargo_group, |metadata| metadata.groups)
+            && matches_metadata(&filter.trailer_category, |metadata| {
+                metadata.trailer_categories
+            })
+            && filter.adr_class.as_deref().is_none_or(|adr_class| {
+                metadata.and_then(|metadata| metadata.adr_class) == Some(adr_class)
+            })
+    }
+}
+
+/// Split `tesco.berlin` or `company.volatile.tesco.berlin` into the company
+/// and the city.
+pub(crate) fn split_company(company: &str) -> (String, String) {
+    let company = company.strip_prefix(COMPANY_PREFIX).unwrap_or(company);
+    match company.split_once('.') {
+        Some((company, city)) => (company.to_string(), city.to_string()),
+        None => (company.to_string(), String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{token, unit_id, unit_ids, SaveBuilder};
+
+    fn offer(
+        cargo: &str,
+        target: &'static str,
+        distance_km: u32,
+    ) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("target", DataValue::String(target)),
+            ("expiration_time", DataValue::UInt32(4_000)),
+            ("urgency", DataValue::UInt32(u32::MAX)),
+            ("shortest_distance_km", DataValue::UInt32(distance_km)),
+            ("cargo", DataValue::Id(unit_id(cargo))),
+            ("trailer_definition", token("trailer_def.scs.box.single_3")),
+            ("units_count", DataValue::UInt32(10)),
+        ]
+    }
+
+    fn sample_market() -> JobMarket {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "company",
+                "company.volatile.tesco.berlin",
+                vec![(
+                    "job_offer",
+                    unit_ids(&["_nameless.10", "_nameless.11", "_nameless.12"]),
+                )],
+            )
+            .unit(
+                "company",
+                "company.volatile.kaarfor.praha",
+                vec![("job_offer", unit_ids(&["_nameless.20"]))],
+            )
+            .unit(
+                "job_offer_data",
+                "_nameless.10",
+                offer("cargo.acid", "kaarfor.praha", 350),
+            )
+            .unit(
+                "job_offer_data",
+                "_nameless.11",
+                offer("cargo.air_mails", "tesco.wien", 680),
+            )
+            .unit("job_offer_data", "_nameless.12", offer("null", "", 0))
+            .unit(
+                "job_offer_data",
+                "_nameless.20",
+                offer("cargo.modded_cargo", "tesco.berlin", 350),
+            )
+            .build();
+        JobMarket::from_bsii(&bsii)
+    }
+
+    #[test]
+    fn reads_job_offers() {
+        let market = sample_market();
+
+        assert_eq!(market.offers.len(), 3);
+        let offer = &market.offers[0];
+        assert_eq!(
+            (offer.source_company.as_str(), offer.source_city.as_str()),
+            ("tesco", "berlin")
+        );
+        assert_eq!(
+            (offer.target_company.as_str(), offer.target_city.as_str()),
+            ("kaarfor", "praha")
+        );
+        assert_eq!(offer.cargo, "acid");
+        assert_eq!(offer.metadata.map(|metadata| metadata.id), Some("acid"));
+        assert_eq!(offer.units_count, 10);
+        assert_eq!(offer.urgency, None);
+        assert_eq!(offer.distance_km, 350);
+        assert_eq!(offer.expiration_time, Some(4_000));
+        assert_eq!(market.offers[2].metadata, None);
+    }
+
+    #[test]
+    fn filters_job_offers() {
+        let market = sample_market();
+        let cargos = |filter| {
+            market
+                .filter(&filter)
+                .into_iter()
+                .map(|offer| offer.cargo.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            cargos(JobFilter {
+                origin_city: Some("berlin".to_string()),
+                ..JobFilter::default()
+            }),
+            ["acid", "air_mails"]
+        );
+        assert_eq!(
+            cargos(JobFilter {
+                adr_class: Some("8".to_string()),
+                ..JobFilter::default()
+            }),
+            ["acid"]
+        );
+        assert_eq!(
+            cargos(JobFilter {
+                cargo_group: Some("containers".to_string()),
+                trailer_category: Some("dryvan".to_string()),
+                ..JobFilter::default()
+            }),
+            ["air_mails"]
+        );
+        assert_eq!(cargos(JobFilter::default()).len(), 3);
+    }
+
+    #[test]
+    fn sorts_by_pay_per_km() {
+        static ACID: CargoMetadata = CargoMetadata {
+            id: "acid",
+            name: "@@cn_acid@@",
+            groups: &["liquid"],
+            adr_class: Some("8"),
+            fragility: None,
+            body_types: &["chemtank"],
+            trailer_categories: &["tr_tank"],
+            unit_reward_per_km: Some("0.5"),
+        };
+        static AIR_MAILS: CargoMetadata = CargoMetadata {
+            unit_reward_per_km: Some("0.75"),
+            ..ACID
+        };
+        let mut market = sample_market();
+        market.offers[0].metadata = Some(&ACID);
+        market.offers[1].metadata = Some(&AIR_MAILS);
+
+        let offers = market
+            .by_pay_per_km(&JobFilter::default())
+            .into_iter()
+            .map(|offer| (offer.cargo.as_str(), offer.pay_per_km()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            offers,
+            [
+                ("air_mails", Some(7.5)),
+                ("acid", Some(5.0)),
+                ("modded_cargo", None)
+            ]
+        );
+        assert_eq!(market.offers[1].base_reward(), Some(5_100.0));
+        assert_eq!(market.offers[2].base_reward(), None);
+    }
+
+    #[test]
+    #[ignore = "requires cargo metadata generated from the game defs"]
+    fn generated_cargos_have_rewards() {
+        let acid = CARGOS.iter().find(|cargo| cargo.id == "acid").unwrap();
+        assert!(acid.unit_reward_per_km.is_some());
+    }
+}
@@ -1,18 +1,60 @@
 mod achievements;
 mod analysis;
+mod bank;
+mod current_job;
+mod discovery;
+mod driver;
+mod economy;
+mod fields;
+mod garage;
 pub mod generated;
+mod job_market;
+mod mods;
+#[cfg(not(target_arch = "wasm32"))]
+mod profile;
+mod progress;
 mod save;
+mod save_info;
+#[cfg(test)]
+mod test_support;
+mod trailer;
+mod truck;
+mod user_profile;
+#[cfg(not(target_arch = "wasm32"))]
+mod watch;
 
 pub use achievements::{
     achievement_categories_for_cargo, evaluate_achievements, is_owned_truck_job_type, Achievement,
-    AchievementEvidence, AchievementProgress, AchievementRegistry, AchievementStatus,
-    EXPERIENCE_BEATS_ALL_CATEGORIES,
+    AchievementChange, AchievementEvidence, AchievementProgress, AchievementRegistry,
+    AchievementStatus, EXPERIENCE_BEATS_ALL_CATEGORIES,
 };
-pub use analysis::{analyze_save_to_json, AnalyzeError};
+pub use analysis::{
+    analyze_save_to_json, load_profile, load_save_game, render_analysis_json, AnalyzeError,
+};
+pub use bank::{Bank, BankError, BankLoan};
+pub use current_job::CurrentJob;
+pub use discovery::{Completion, Discovery};
+pub use driver::{Driver, DriverJob, DriverSkills};
+pub use economy::{Economy, EconomyError};
+pub use garage::{Garage, ProfitLogEntry};
+pub use job_market::{JobFilter, JobMarket, JobOffer};
+pub use mods::{save_dependencies, ModReport, OrderDifference};
+#[cfg(not(target_arch = "wasm32"))]
+pub use profile::{
+    decode_profile_dir_name, list_profiles, ProfileDir, ProfileDirError, SaveDir, GAME_FILE,
+    INFO_FILE, PROFILE_FILE,
+};
+pub use progress::{PlayerProgress, PlayerSkills};
 pub use save::{
     DeliveryAnalytics, DeliveryLog, DeliveryLogEntry, SaveGame, SaveGameError, CARGO_PREFIX,
     COMPANY_PREFIX, VEHICLE_PREFIX,
 };
+pub use save_info::{Dependency, SaveInfo, SaveInfoError};
+pub use trailer::Trailer;
+pub use truck::{Accessory, Truck, Wear};
+pub use user_profile::{ActiveMod, Profile, ProfileError};
+#[cfg(not(target_arch = "wasm32"))]
+pub use watch::SaveWatcher;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct CargoMetadata {
@@ -23,4 +65,13 @@ pub struct CargoMetadata {
     pub fragility: Option<&'static str>,
     pub body_types: &'static [&'static str],
     pub trailer_categories: &'static [&'static str],
+    /// The reward per km for one unit of the cargo.
+    pub unit_reward_per_km: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CityMetadata {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub country: &'static str,
 }
@@ -0,0 +1,175 @@
+use crate::bsii_file::BsiiFile;
+use crate::ets2::fields::strings;
+use crate::ets2::{ActiveMod, Dependency};
+
+const MOD_KIND: &str = "mod";
+
+/// Compares the mods required by a save with the mods active in a profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModReport {
+    /// The mods the save depends on, in the order written by the game.
+    pub required: Vec<Dependency>,
+    /// The mods active in the profile, with the highest priority first.
+    pub active: Vec<ActiveMod>,
+    /// Required mods which are not active in the profile.
+    pub missing: Vec<Dependency>,
+    /// Mods which are active in the profile but not required by the save.
+    pub unused: Vec<ActiveMod>,
+    pub order_differences: Vec<OrderDifference>,
+}
+
+/// A mod which is both required and active, but loaded at another position
+/// than when the save was written.
+///
+/// Positions only count the mods in both lists, so that missing and unused
+/// mods do not shift them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderDifference {
+    pub id: String,
+    pub save_position: usize,
+    pub profile_position: usize,
+}
+
+/// Read the dependencies of a save from the `dependencies` field of any unit,
+/// which is written to the `save_container` of `info.sii` and, by some game
+/// versions, to `game.sii`.
+pub fn save_dependencies(bsii: &BsiiFile<'_>) -> Vec<Dependency> {
+    bsii.data_blocks()
+        .map(|block| strings(bsii, block, "dependencies"))
+        .find(|dependencies| !dependencies.is_empty())
+        .unwrap_or_default()
+        .iter()
+        .map(|dependency| Dependency::parse(dependency))
+        .collect()
+}
+
+impl ModReport {
+    /// Build the report for the dependencies of a save. DLC dependencies are
+    /// ignored, as they are not listed in the active mods of a profile.
+    pub fn new(dependencies: &[Dependency], active_mods: &[ActiveMod]) -> Self {
+        let required = dependencies
+            .iter()
+            .filter(|dependency| dependency.kind == MOD_KIND)
+            .cloned()
+            .collect::<Vec<_>>();
+        let is_active = |id: &str| active_mods.iter().any(|active_mod| active_mod.id == id);
+        let is_required = |id: &str| required.iter().any(|dependency| dependency.id == id);
+
+        let missing = required
+            .iter()
+            .filter(|dependency| !is_active(&dependency.id))
+            .cloned()
+            .collect();
+        let unused = active_mods
+            .iter()
+            .filter(|active_mod| !is_required(&active_mod.id))
+            .cloned()
+            .collect();
+
+        let save_order = required
+            .iter()
+            .map(|dependency| dependency.id.as_str())
+            .filter(|id| is_active(id))
+            .collect::<Vec<_>>();
+        let profile_order = active_mods
+            .iter()
+            .map(|active_mod| active_mod.id.as_str())
+            .filter(|id| is_required(id))
+            .collect::<Vec<_>>();
+        let order_differences = save_order
+            .iter()
+            .enumerate()
+            .filter_map(|(save_position, id)| {
+                let profile_position = profile_order.iter().position(|other| other == id)?;
+                (profile_position != save_position).then(|| OrderDifference {
+                    id: id.to_string(),
+                    save_position,
+                    profile_position,
+                })
+            })
+            .collect();
+
+        Self {
+            required,
+            active: active_mods.to_vec(),
+            missing,
+            unused,
+            order_differences,
+        }
+    }
+
+    /// Whether every required mod is active in the same order.
+    pub fn is_compatible(&self) -> bool {
+        self.missing.is_empty() && self.order_differences.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependencies(values: &[&str]) -> Vec<Dependency> {
+        values
+            .iter()
+            .map(|value| Dependency::parse(value))
+            .collect()
+    }
+
+    fn active_mods(values: &[&str]) -> Vec<ActiveMod> {
+        values.iter().map(|value| ActiveMod::parse(value)).collect()
+    }
+
+    #[test]
+    fn reports_missing_unused_and_reordered_mods() {
+        let report = ModReport::new(
+            &dependencies(&[
+                "dlc|eut2_east|Going East!",
+                "mod|promods-def|ProMods Definition",
+                "mod|promods|ProMods",
+                "mod|sound_fix|Sound Fixes",
+            ]),
+            &active_mods(&[
+                "promods|ProMods",
+                "skin_pack|Skins",
+                "promods-def|ProMods Definition",
+            ]),
+        );
+
+        assert_eq!(
+            report
+                .required
+                .iter()
+                .map(|dependency| dependency.id.as_str())
+                .collect::<Vec<_>>(),
+            ["promods-def", "promods", "sound_fix"]
+        );
+        assert_eq!(report.missing, dependencies(&["mod|sound_fix|Sound Fixes"]));
+        assert_eq!(report.unused, active_mods(&["skin_pack|Skins"]));
+        assert_eq!(
+            report.order_differences,
+            [
+                OrderDifference {
+                    id: "promods-def".to_string(),
+                    save_position: 0,
+                    profile_position: 1,
+                },
+                OrderDifference {
+                    id: "promods".to_string(),
+                    save_position: 1,
+                    profile_position: 0,
+                },
+            ]
+        );
+        assert!(!report.is_compatible());
+    }
+
+    #[test]
+    fn accepts_profile_with_extra_mods() {
+        let report = ModReport::new(
+            &dependencies(&["mod|promods|ProMods"]),
+            &active_mods(&["skin_pack|Skins", "promods|ProMods"]),
+        );
+
+        assert!(report.is_compatible());
+    }
+}
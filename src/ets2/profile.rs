@@ -0,0 +1,291 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::bsii_file::BsiiFile;
+use crate::ets2::{Profile, ProfileError, SaveInfo, SaveInfoError};
+use crate::file_type::{decode_until_bsii, DecodeError};
+
+pub const PROFILE_FILE: &str = "profile.sii";
+pub const INFO_FILE: &str = "info.sii";
+pub const GAME_FILE: &str = "game.sii";
+pub(super) const SAVE_DIR: &str = "save";
+
+/// A profile directory, e.g. `profiles/4A6F686E/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileDir {
+    pub path: PathBuf,
+    /// The name of the directory, the hex encoding of the profile name.
+    pub dir_name: String,
+    /// The decoded profile name, if the directory name is valid hex.
+    pub name: Option<String>,
+    /// The save slots of the profile, sorted by directory name.
+    pub saves: Vec<SaveDir>,
+}
+
+/// A save slot directory, e.g. `save/autosave/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDir {
+    pub path: PathBuf,
+    pub dir_name: String,
+    pub info_path: Option<PathBuf>,
+    pub game_path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum ProfileDirError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Decode {
+        path: PathBuf,
+        source: DecodeError,
+    },
+    Profile {
+        path: PathBuf,
+        source: ProfileError,
+    },
+    SaveInfo {
+        path: PathBuf,
+        source: SaveInfoError,
+    },
+    MissingFile(PathBuf),
+}
+
+impl std::fmt::Display for ProfileDirError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileDirError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            ProfileDirError::Decode { path, source } => write!(f, "{}: {}", path.display(), source),
+            ProfileDirError::Profile { path, source } => {
+                write!(f, "{}: {}", path.display(), source)
+            }
+            ProfileDirError::SaveInfo { path, source } => {
+                write!(f, "{}: {}", path.display(), source)
+            }
+            ProfileDirError::MissingFile(path) => write!(f, "{}: missing file", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ProfileDirError {}
+
+/// List the profiles in a `profiles/` or `steam_profiles/` directory, sorted
+/// by directory name. Directories without a `profile.sii` are skipped.
+pub fn list_profiles(profiles_dir: impl AsRef<Path>) -> io::Result<Vec<ProfileDir>> {
+    let mut profiles = Vec::new();
+    for path in sorted_dirs(profiles_dir.as_ref())? {
+        if path.join(PROFILE_FILE).is_file() {
+            profiles.push(ProfileDir::open(path)?);
+        }
+    }
+    Ok(profiles)
+}
+
+/// Decode a profile directory name, which is the profile name encoded as
+/// uppercase hex of its UTF-8 bytes.
+pub fn decode_profile_dir_name(dir_name: &str) -> Option<String> {
+    if dir_name.is_empty() || !dir_name.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..dir_name.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(dir_name.get(index..index + 2)?, 16).ok())
+        .collect::<Option<Vec<_>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+impl ProfileDir {
+    /// Read a profile directory and list its save slots.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let dir_name = file_name(&path);
+        let save_dir = path.join(SAVE_DIR);
+        let saves = if save_dir.is_dir() {
+            sorted_dirs(&save_dir)?
+                .into_iter()
+                .map(SaveDir::new)
+                .collect()
+        } else {
+            vec![]
+        };
+        Ok(Self {
+            name: decode_profile_dir_name(&dir_name),
+            dir_name,
+            path,
+            saves,
+        })
+    }
+
+    pub fn profile_path(&self) -> PathBuf {
+        self.path.join(PROFILE_FILE)
+    }
+
+    /// Read `profile.sii`, decoded to binary BSII.
+    pub fn read_profile(&self) -> Result<Vec<u8>, ProfileDirError> {
+        read_bsii(&self.profile_path())
+    }
+
+    /// Read the profile from `profile.sii`.
+    pub fn read_user_profile(&self) -> Result<Profile, ProfileDirError> {
+        let path = self.profile_path();
+        let content = read_bsii(&path)?;
+        let bsii = parse_bsii(&path, &content)?;
+        Profile::from_bsii(&bsii).map_err(|source| ProfileDirError::Profile { path, source })
+    }
+
+    /// Find a save slot by its directory name.
+    pub fn save(&self, dir_name: &str) -> Option<&SaveDir> {
+        self.saves.iter().find(|save| save.dir_name == dir_name)
+    }
+}
+
+impl SaveDir {
+    fn new(path: PathBuf) -> Self {
+        let existing = |name| Some(path.join(name)).filter(|path| path.is_file());
+        Self {
+            dir_name: file_name(&path),
+            info_path: existing(INFO_FILE),
+            game_path: existing(GAME_FILE),
+            path,
+        }
+    }
+
+    /// Read `info.sii`, decoded to binary BSII.
+    pub fn read_info(&self) -> Result<Vec<u8>, ProfileDirError> {
+        read_bsii(&self.path.join(INFO_FILE))
+    }
+
+    /// Read the save metadata from `info.sii`.
+    pub fn read_save_info(&self) -> Result<SaveInfo, ProfileDirError> {
+        let path = self.path.join(INFO_FILE);
+        let content = read_bsii(&path)?;
+        let bsii = parse_bsii(&path, &content)?;
+        SaveInfo::from_bsii(&bsii).map_err(|source| ProfileDirError::SaveInfo { path, source })
+    }
+
+    /// Read `game.sii`, decoded to binary BSII.
+    pub fn read_game(&self) -> Result<Vec<u8>, ProfileDirError> {
+        read_bsii(&self.path.join(GAME_FILE))
+    }
+}
+
+fn read_bsii(path: &Path) -> Result<Vec<u8>, ProfileDirError> {
+    if !path.is_file() {
+        return Err(ProfileDirError::MissingFile(path.to_path_buf()));
+    }
+    let content = fs::read(path).map_err(|source| ProfileDirError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let bsii_content = decode_until_bsii(&content).map_err(|source| ProfileDirError::Decode {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(bsii_content.into_owned())
+}
+
+fn parse_bsii<'a>(path: &Path, content: &'a [u8]) -> Result<BsiiFile<'a>, ProfileDirError> {
+    BsiiFile::parse(content).map_err(|err| ProfileDirError::Decode {
+        path: path.to_path_buf(),
+        source: err.into(),
+    })
+}
+
+fn sorted_dirs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            dirs.push(entry.path());
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_BSII: &[u8] = &[
+        b'B', b'S', b'I', b'I', 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn decodes_hex_directory_names() {
+        assert_eq!(decode_profile_dir_name("4A6F686E").as_deref(), Some("John"));
+        assert_eq!(
+            decode_profile_dir_name("c39c6265722044726976").as_deref(),
+            Some("Über Driv")
+        );
+        assert_eq!(decode_profile_dir_name("4A6"), None);
+        assert_eq!(decode_profile_dir_name("zz"), None);
+        assert_eq!(decode_profile_dir_name(""), None);
+    }
+
+    #[test]
+    fn lists_profiles_and_saves() {
+        let profiles_dir =
+            std::env::temp_dir().join(format!("sii-decode-profiles-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&profiles_dir);
+        let profile_dir = profiles_dir.join("4A6F686E");
+        fs::create_dir_all(profile_dir.join("save").join("autosave")).unwrap();
+        fs::create_dir_all(profile_dir.join("save").join("1")).unwrap();
+        fs::create_dir_all(profiles_dir.join("not_a_profile")).unwrap();
+        fs::write(profile_dir.join(PROFILE_FILE), MINIMAL_BSII).unwrap();
+        fs::write(
+            profile_dir.join("save/autosave").join(INFO_FILE),
+            MINIMAL_BSII,
+        )
+        .unwrap();
+        fs::write(
+            profile_dir.join("save/autosave").join(GAME_FILE),
+            b"SiiNunit\n{\n}\n",
+        )
+        .unwrap();
+
+        let profiles = list_profiles(&profiles_dir).unwrap();
+
+        assert_eq!(profiles.len(), 1);
+        let profile = &profiles[0];
+        assert_eq!(profile.name.as_deref(), Some("John"));
+        assert_eq!(profile.read_profile().unwrap(), MINIMAL_BSII);
+        assert!(matches!(
+            profile.read_user_profile(),
+            Err(ProfileDirError::Profile { .. })
+        ));
+        assert_eq!(
+            profile
+                .saves
+                .iter()
+                .map(|save| save.dir_name.as_str())
+                .collect::<Vec<_>>(),
+            ["1", "autosave"]
+        );
+        let autosave = profile.save("autosave").unwrap();
+        assert!(autosave.info_path.is_some());
+        assert_eq!(autosave.read_info().unwrap(), MINIMAL_BSII);
+        assert!(matches!(
+            autosave.read_save_info(),
+            Err(ProfileDirError::SaveInfo { .. })
+        ));
+        assert!(matches!(
+            autosave.read_game(),
+            Err(ProfileDirError::Decode { .. })
+        ));
+        assert!(matches!(
+            profile.save("1").unwrap().read_info(),
+            Err(ProfileDirError::MissingFile(_))
+        ));
+
+        fs::remove_dir_all(&profiles_dir).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+use crate::bsii_file::BsiiFile;
+use crate::ets2::{Economy, EconomyError};
+
+/// Reaching level `n` from level `n - 1` costs `n` times this experience.
+const LEVEL_EXPERIENCE_STEP: i64 = 100;
+
+/// The number of ADR classes the player can unlock.
+const ADR_CLASS_COUNT: u32 = 6;
+
+/// The player's experience and skills, read from the `economy` unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerProgress {
+    pub experience_points: i64,
+    pub skills: PlayerSkills,
+}
+
+/// The skill points allocated by the player.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerSkills {
+    /// The unlocked ADR classes as a bit mask, one bit per class.
+    pub adr: i64,
+    pub long_distance: i64,
+    pub high_value: i64,
+    pub fragile: i64,
+    pub urgent: i64,
+    /// Ecodriving in the game UI.
+    pub mechanical: i64,
+}
+
+impl PlayerProgress {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, EconomyError> {
+        Economy::from_bsii(bsii).map(|economy| Self::from_economy(&economy))
+    }
+
+    pub fn from_economy(economy: &Economy) -> Self {
+        Self {
+            experience_points: economy.experience_points,
+            skills: economy.skills.clone(),
+        }
+    }
+
+    /// The level reached with the experience, starting at 1.
+    pub fn level(&self) -> i64 {
+        let mut level = 1;
+        while self.experience_points >= level_experience(level + 1) {
+            level += 1;
+        }
+        level
+    }
+
+    /// The experience still needed to reach the next level.
+    pub fn experience_to_next_level(&self) -> i64 {
+        level_experience(self.level() + 1) - self.experience_points
+    }
+
+    /// The skill points not allocated yet. Every level after the first
+    /// grants one point.
+    pub fn unspent_skill_points(&self) -> i64 {
+        (self.level() - 1 - self.skills.allocated_points()).max(0)
+    }
+}
+
+impl PlayerSkills {
+    /// The skill points spent on all skills, one for each ADR class.
+    pub fn allocated_points(&self) -> i64 {
+        let adr_mask = (1 << ADR_CLASS_COUNT) - 1;
+        i64::from((self.adr & adr_mask).count_ones())
+            + self.long_distance
+            + self.high_value
+            + self.fragile
+            + self.urgent
+            + self.mechanical
+    }
+}
+
+/// The total experience needed to reach a level.
+///
+/// The thresholds are not read from the game defs: they follow the rule of
+/// [`LEVEL_EXPERIENCE_STEP`], i.e. 200 for level 2, 300 more for level 3 and
+/// so on, without an upper bound.
+fn level_experience(level: i64) -> i64 {
+    if level < 2 {
+        return 0;
+    }
+    LEVEL_EXPERIENCE_STEP * (level * (level + 1) / 2 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::SaveBuilder;
+
+    fn progress(experience_points: i64) -> PlayerProgress {
+        PlayerProgress {
+            experience_points,
+            skills: PlayerSkills::default(),
+        }
+    }
+
+    #[test]
+    fn reads_progress_from_economy() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "economy",
+                "_nameless.1",
+                vec![
+                    ("experience_points", DataValue::UInt32(1_500)),
+                    ("adr", DataValue::UInt32(0b101)),
+                    ("long_dist", DataValue::UInt32(1)),
+                    ("heavy", DataValue::UInt32(0)),
+                    ("fragile", DataValue::UInt32(0)),
+                    ("urgent", DataValue::UInt32(0)),
+                    ("mechanical", DataValue::UInt32(1)),
+                ],
+            )
+            .build();
+
+        let progress = PlayerProgress::from_bsii(&bsii).unwrap();
+
+        assert_eq!(progress.skills.adr, 0b101);
+        assert_eq!(progress.skills.allocated_points(), 4);
+        assert_eq!(progress.level(), 5);
+        assert_eq!(progress.experience_to_next_level(), 500);
+        assert_eq!(progress.unspent_skill_points(), 0);
+        assert_eq!(
+            PlayerProgress::from_bsii(&SaveBuilder::new().build()),
+            Err(EconomyError::MissingEconomy)
+        );
+    }
+
+    #[test]
+    fn computes_level_from_experience() {
+        assert_eq!(progress(0).level(), 1);
+        assert_eq!(progress(0).experience_to_next_level(), 200);
+        assert_eq!(progress(199).level(), 1);
+        assert_eq!(progress(200).level(), 2);
+        assert_eq!(progress(200).unspent_skill_points(), 1);
+        assert_eq!(progress(49_499).level(), 30);
+        assert_eq!(progress(49_500).level(), 31);
+        assert_eq!(progress(49_500).experience_to_next_level(), 3_200);
+        assert_eq!(progress(52_699).level(), 31);
+        assert_eq!(progress(52_700).level(), 32);
+    }
+}
@@ -1,8 +1,13 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::bsii_file::{BsiiFile, DataBlock, DataValue};
+use crate::csv::write_csv_row;
+use crate::ets2::fields::Units;
 use crate::ets2::generated::cargo_metadata::CARGOS;
-use crate::ets2::CargoMetadata;
+use crate::ets2::{
+    Bank, CargoMetadata, CurrentJob, Discovery, Driver, Economy, Garage, JobMarket, PlayerProgress,
+    Trailer, Truck,
+};
 
 pub const COMPANY_PREFIX: &str = "company.volatile.";
 pub const CARGO_PREFIX: &str = "cargo.";
@@ -19,12 +24,30 @@ const TRUCK_PARAM: usize = 16;
 const JOB_TYPE_PARAM: usize = 18;
 const MIN_PARAMS_LEN: usize = JOB_TYPE_PARAM + 1;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct SaveGame {
     pub delivery_log: DeliveryLog,
+    /// The `economy` unit, missing from saves that only hold a delivery log.
+    pub economy: Option<Economy>,
+    pub bank: Option<Bank>,
+    /// The player's experience and skills, read from the `economy` unit.
+    pub progress: Option<PlayerProgress>,
+    pub discovery: Option<Discovery>,
+    /// The trucks owned by the player.
+    pub trucks: Vec<Truck>,
+    /// The trailers owned by the player, with their slave trailers.
+    pub trailers: Vec<Trailer>,
+    /// The garages of all cities, owned or not.
+    pub garages: Vec<Garage>,
+    /// The drivers hired in any garage.
+    pub drivers: Vec<Driver>,
+    /// The job offers of all companies.
+    pub job_market: JobMarket,
+    /// The job the player is on when the save was taken.
+    pub current_job: Option<CurrentJob>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct DeliveryLog {
     pub entries: Vec<DeliveryLogEntry>,
 }
@@ -38,6 +61,8 @@ pub struct DeliveryLogEntry {
     pub revenue: f64,
     pub truck: String,
     pub job_type: String,
+    /// All params of the entry, including the ones not modeled above.
+    pub params: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -72,9 +97,25 @@ impl std::fmt::Display for SaveGameError {
 impl std::error::Error for SaveGameError {}
 
 impl SaveGame {
+    /// Read the save. Only the delivery log is required; the models that
+    /// cannot be read are logged as warnings and left empty.
     pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, SaveGameError> {
         let delivery_log = DeliveryLog::from_bsii(bsii)?;
-        Ok(Self { delivery_log })
+        let units = Units::new(bsii);
+        let economy = optional_model("economy", Economy::from_bsii(bsii));
+        Ok(Self {
+            delivery_log,
+            progress: economy.as_ref().map(PlayerProgress::from_economy),
+            discovery: economy.as_ref().map(Discovery::from_economy),
+            economy,
+            bank: optional_model("bank", Bank::from_units(&units)),
+            trucks: Truck::all_from_units(&units),
+            trailers: Trailer::all_from_units(&units),
+            garages: Garage::all_from_units(&units),
+            drivers: Driver::all_from_units(&units),
+            job_market: JobMarket::from_units(&units),
+            current_job: CurrentJob::from_units(&units),
+        })
     }
 
     pub fn analytics(&self) -> DeliveryAnalytics {
@@ -82,6 +123,12 @@ impl SaveGame {
     }
 }
 
+fn optional_model<T>(name: &str, model: Result<T, impl std::fmt::Display>) -> Option<T> {
+    model
+        .inspect_err(|err| log::warn!("skipping the {name} of the save: {err}"))
+        .ok()
+}
+
 impl DeliveryLog {
     pub fn from_entries(entries: Vec<DeliveryLogEntry>) -> Self {
         Self { entries }
@@ -157,6 +204,47 @@ impl DeliveryLog {
         coverage
     }
 
+    /// Export the entries as CSV, with a column for each modeled field
+    /// followed by a column for each param.
+    pub fn to_csv(&self) -> String {
+        let params_len = self
+            .entries
+            .iter()
+            .map(|entry| entry.params.len())
+            .max()
+            .unwrap_or(0);
+        let mut header = [
+            "source_company",
+            "destination_company",
+            "cargo",
+            "distance_km",
+            "revenue",
+            "truck",
+            "job_type",
+        ]
+        .map(ToString::to_string)
+        .to_vec();
+        header.extend((0..params_len).map(|index| format!("params[{index}]")));
+
+        let mut output = String::new();
+        write_csv_row(&mut output, &header);
+        for entry in &self.entries {
+            let mut row = vec![
+                entry.source_company.clone(),
+                entry.destination_company.clone(),
+                entry.cargo.clone(),
+                entry.distance_km.to_string(),
+                entry.revenue.to_string(),
+                entry.truck.clone(),
+                entry.job_type.clone(),
+            ];
+            row.extend(entry.params.iter().cloned());
+            row.resize(header.len(), String::new());
+            write_csv_row(&mut output, &row);
+        }
+        output
+    }
+
     pub fn analytics(&self) -> DeliveryAnalytics {
         DeliveryAnalytics {
             delivery_count: self.delivery_count(),
@@ -184,6 +272,7 @@ impl DeliveryLogEntry {
             revenue: params[REVENUE_PARAM].parse().ok()?,
             truck: params[TRUCK_PARAM].to_string(),
             job_type: params[JOB_TYPE_PARAM].to_string(),
+            params: params.iter().map(ToString::to_string).collect(),
         })
     }
 
@@ -360,6 +449,25 @@ mod tests {
         assert_eq!(entry.revenue, 16930.0);
         assert_eq!(truck_brand(&entry.truck), "mercedes");
         assert_eq!(entry.job_type, "quick");
+        assert_eq!(entry.params.len(), params.len());
+    }
+
+    #[test]
+    fn exports_delivery_log_with_all_params() {
+        let bsii = synthetic_bsii(vec![Id::Nameless(10)]);
+        let save = SaveGame::from_bsii(&bsii).unwrap();
+
+        let csv = save.delivery_log.to_csv();
+        let mut lines = csv.lines();
+
+        let header = lines.next().unwrap();
+        assert!(header.starts_with("source_company,destination_company,cargo,"));
+        assert!(header.ends_with(",params[21],params[22]"));
+        assert_eq!(
+            lines.next().unwrap(),
+            "company.volatile.lkwlog.amsterdam,company.volatile.stokes.amsterdam,cargo.gravel,362,16930,vehicle.mercedes.actros,quick,605,company.volatile.lkwlog.amsterdam,company.volatile.stokes.amsterdam,cargo.gravel,16,16930.000,362,0.000,295,0,0,1,1,16930.000,0,600,vehicle.mercedes.actros,362,quick,,,0,25000.000"
+        );
+        assert_eq!(lines.next(), None);
     }
 
     #[test]
@@ -495,6 +603,7 @@ mod tests {
             revenue,
             truck: truck.to_string(),
             job_type: job_type.to_string(),
+            params: vec![],
         }
     }
 
@@ -0,0 +1,159 @@
+use crate::bsii_file::BsiiFile;
+use crate::ets2::fields::{float, integer, string, strings};
+
+/// The metadata of a save, read from the `save_container` unit of its
+/// `info.sii`.
+///
+/// The `info_*` fields are stats cached by the game when saving, which are not
+/// written by older game versions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveInfo {
+    /// The name given to the save, empty for autosaves and quicksaves.
+    pub name: String,
+    /// The in-game time in minutes.
+    pub time: i64,
+    /// The time the save was written as a Unix timestamp.
+    pub file_time: i64,
+    pub version: i64,
+    pub dependencies: Vec<Dependency>,
+    pub players_experience: Option<i64>,
+    pub money_account: Option<i64>,
+    pub visited_cities: Option<i64>,
+    pub unlocked_dealers: Option<i64>,
+    pub unlocked_recruitments: Option<i64>,
+    pub explored_ratio: Option<f64>,
+}
+
+/// A DLC or mod required by a save, written as `kind|id|name`, e.g.
+/// `dlc|eut2_east|Going East!` or `mod|promods|ProMods`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    pub kind: String,
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SaveInfoError {
+    MissingSaveContainer,
+}
+
+impl std::fmt::Display for SaveInfoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveInfoError::MissingSaveContainer => write!(f, "missing save_container block"),
+        }
+    }
+}
+
+impl std::error::Error for SaveInfoError {}
+
+impl SaveInfo {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, SaveInfoError> {
+        let block = bsii
+            .blocks_by_prototype_name("save_container")
+            .next()
+            .ok_or(SaveInfoError::MissingSaveContainer)?;
+        Ok(Self {
+            name: string(bsii, block, "name").unwrap_or_default(),
+            time: integer(bsii, block, "time").unwrap_or(0),
+            file_time: integer(bsii, block, "file_time").unwrap_or(0),
+            version: integer(bsii, block, "version").unwrap_or(0),
+            dependencies: strings(bsii, block, "dependencies")
+                .iter()
+                .map(|dependency| Dependency::parse(dependency))
+                .collect(),
+            players_experience: integer(bsii, block, "info_players_experience"),
+            money_account: integer(bsii, block, "info_money_account"),
+            visited_cities: integer(bsii, block, "info_visited_cities"),
+            unlocked_dealers: integer(bsii, block, "info_unlocked_dealers"),
+            unlocked_recruitments: integer(bsii, block, "info_unlocked_recruitments"),
+            explored_ratio: float(bsii, block, "info_explored_ratio"),
+        })
+    }
+}
+
+impl Dependency {
+    /// Parse a dependency. Values without a kind keep the whole value as ID
+    /// and name.
+    pub fn parse(value: &str) -> Self {
+        let mut parts = value.splitn(3, '|');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(kind), Some(id), name) => Self {
+                kind: kind.to_string(),
+                id: id.to_string(),
+                name: name.unwrap_or(id).to_string(),
+            },
+            _ => Self {
+                kind: String::new(),
+                id: value.to_string(),
+                name: value.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::SaveBuilder;
+
+    #[test]
+    fn reads_save_container() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "save_container",
+                "_nameless.1234",
+                vec![
+                    ("name", DataValue::String("Before the trip")),
+                    ("time", DataValue::UInt32(4321)),
+                    ("file_time", DataValue::Int32(1_700_000_000)),
+                    ("version", DataValue::UInt32(73)),
+                    (
+                        "dependencies",
+                        DataValue::StringArray(vec!["dlc|eut2_east|Going East!", "rusmap"]),
+                    ),
+                    ("info_players_experience", DataValue::UInt32(u32::MAX)),
+                    ("info_money_account", DataValue::Int64(-500)),
+                    ("info_explored_ratio", DataValue::Float(0.25)),
+                ],
+            )
+            .build();
+
+        let info = SaveInfo::from_bsii(&bsii).unwrap();
+
+        assert_eq!(info.name, "Before the trip");
+        assert_eq!(
+            (info.time, info.file_time, info.version),
+            (4321, 1_700_000_000, 73)
+        );
+        assert_eq!(
+            info.dependencies,
+            [
+                Dependency {
+                    kind: "dlc".to_string(),
+                    id: "eut2_east".to_string(),
+                    name: "Going East!".to_string(),
+                },
+                Dependency {
+                    kind: String::new(),
+                    id: "rusmap".to_string(),
+                    name: "rusmap".to_string(),
+                },
+            ]
+        );
+        assert_eq!(info.players_experience, None);
+        assert_eq!(info.money_account, Some(-500));
+        assert_eq!(info.visited_cities, None);
+        assert_eq!(info.explored_ratio, Some(0.25));
+    }
+
+    #[test]
+    fn requires_save_container() {
+        assert_eq!(
+            SaveInfo::from_bsii(&SaveBuilder::new().build()),
+            Err(SaveInfoError::MissingSaveContainer)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Builds synthetic BSII files for the tests of the save models.
+
+use std::collections::HashMap;
+
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id, Prototype, ValuePrototype};
+
+/// Collects units into a BSII file, creating a prototype for every class on
+/// its first unit. All units of a class must have the same fields.
+#[derive(Default)]
+pub(crate) struct SaveBuilder {
+    prototypes: HashMap<u32, Prototype<'static>>,
+    data_blocks: Vec<DataBlock<'static>>,
+}
+
+impl SaveBuilder {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn unit(
+        mut self,
+        class: &'static str,
+        id: &str,
+        fields: Vec<(&'static str, DataValue<'static>)>,
+    ) -> Self {
+        let prototype_id = match self
+            .prototypes
+            .values()
+            .find(|prototype| prototype.name == class)
+        {
+            Some(prototype) => {
+                assert!(
+                    prototype
+                        .fields()
+                        .map(|field| field.name)
+                        .eq(fields.iter().map(|(name, _)| *name)),
+                    "fields of {class} differ between units"
+                );
+                prototype.id()
+            }
+            None => {
+                let prototype_id = self.prototypes.len() as u32 + 1;
+                let value_prototypes = fields
+                    .iter()
+                    .map(|(name, value)| ValuePrototype {
+                        type_id: type_id(value),
+                        name,
+                        enum_values: None,
+                    })
+                    .collect();
+                self.prototypes.insert(
+                    prototype_id,
+                    Prototype {
+                        id: prototype_id,
+                        name: class,
+                        value_prototypes,
+                    },
+                );
+                prototype_id
+            }
+        };
+        self.data_blocks.push(DataBlock {
+            prototype_id,
+            id: unit_id(id),
+            data: fields.into_iter().map(|(_, value)| value).collect(),
+        });
+        self
+    }
+
+    pub(crate) fn build(self) -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: self.prototypes,
+            data_blocks: self.data_blocks,
+        }
+    }
+}
+
+pub(crate) fn unit_id(text: &str) -> Id {
+    Id::parse(text).unwrap()
+}
+
+pub(crate) fn unit_ids(texts: &[&str]) -> DataValue<'static> {
+    DataValue::IdArray(texts.iter().map(|text| unit_id(text)).collect())
+}
+
+pub(crate) fn token(text: &str) -> DataValue<'static> {
+    DataValue::EncodedString(text.to_string())
+}
+
+pub(crate) fn tokens(texts: &[&str]) -> DataValue<'static> {
+    DataValue::EncodedStringArray(texts.iter().map(ToString::to_string).collect())
+}
+
+/// The fields of a `profit_log_entry` unit, with 300 of maintenance and fuel
+/// costs over 500 km.
+pub(crate) fn profit_log_entry(
+    revenue: u32,
+    wage: u32,
+    day: u32,
+) -> Vec<(&'static str, DataValue<'static>)> {
+    vec![
+        ("revenue", DataValue::UInt32(revenue)),
+        ("wage", DataValue::UInt32(wage)),
+        ("maintenance", DataValue::UInt32(100)),
+        ("fuel", DataValue::UInt32(200)),
+        ("distance", DataValue::UInt32(500)),
+        ("cargo_count", DataValue::UInt32(1)),
+        ("timestamp_day", DataValue::UInt32(day)),
+    ]
+}
+
+fn type_id(value: &DataValue<'_>) -> u32 {
+    match value {
+        DataValue::String(_) => 0x01,
+        DataValue::StringArray(_) => 0x02,
+        DataValue::EncodedString(_) => 0x03,
+        DataValue::EncodedStringArray(_) => 0x04,
+        DataValue::Float(_) => 0x05,
+        DataValue::FloatArray(_) => 0x06,
+        DataValue::FloatVec2(_) => 0x07,
+        DataValue::FloatVec3(_) => 0x09,
+        DataValue::FloatVec3Array(_) => 0x0a,
+        DataValue::Int32Vec3(_) => 0x11,
+        DataValue::Int32Vec3Array(_) => 0x12,
+        DataValue::FloatVec4(_) => 0x17,
+        DataValue::FloatVec4Array(_) => 0x18,
+        DataValue::FloatVec8(_) => 0x19,
+        DataValue::FloatVec8Array(_) => 0x1a,
+        DataValue::Int32(_) => 0x25,
+        DataValue::Int32Array(_) => 0x26,
+        DataValue::UInt32(_) => 0x27,
+        DataValue::UInt32Array(_) => 0x28,
+        DataValue::UInt16(_) => 0x2b,
+        DataValue::UInt16Array(_) => 0x2c,
+        DataValue::Int64(_) => 0x31,
+        DataValue::Int64Array(_) => 0x32,
+        DataValue::UInt64(_) => 0x33,
+        DataValue::UInt64Array(_) => 0x34,
+        DataValue::Bool(_) => 0x35,
+        DataValue::BoolArray(_) => 0x36,
+        DataValue::Enum(_) => 0x37,
+        DataValue::Id(_) => 0x39,
+        DataValue::IdArray(_) => 0x3a,
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::bsii_file::{BsiiFile, DataBlock, Id};
+use crate::ets2::fields::{integer, string, Units};
+use crate::ets2::generated::cargo_metadata::CARGOS;
+use crate::ets2::truck::{garage_slots, GarageSlot};
+use crate::ets2::{Accessory, CargoMetadata, Wear};
+
+/// An owned trailer, read from a `trailer` unit with its `trailer_def` and
+/// accessories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trailer {
+    pub id: String,
+    /// The trailer model, e.g. `scs.box`, from the vehicle data accessory.
+    pub model: Option<String>,
+    /// The body type matched against [`CargoMetadata::body_types`], e.g.
+    /// `dryvan`.
+    pub body_type: Option<String>,
+    /// How the trailer is coupled, e.g. `single`, `double` or `b_double`.
+    pub chain_type: Option<String>,
+    pub chassis: Option<String>,
+    /// The license plate with its formatting tags and the country after `|`.
+    pub license_plate: String,
+    pub odometer: i64,
+    pub wear: Wear,
+    pub accessories: Vec<Accessory>,
+    /// The garage the trailer is parked at.
+    pub garage: Option<String>,
+    /// The next trailer of a double or B-double.
+    pub slave_trailer: Option<Box<Trailer>>,
+}
+
+impl Trailer {
+    /// Read the trailers owned by the player, or all trailers that are not
+    /// the slave of another trailer if the save has no `player` unit.
+    pub fn all_from_bsii(bsii: &BsiiFile<'_>) -> Vec<Self> {
+        Self::all_from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn all_from_units(units: &Units<'_, '_>) -> Vec<Self> {
+        let bsii = units.bsii;
+        let blocks = match bsii.blocks_by_prototype_name("player").next() {
+            Some(player) => units.references(player, "trailers"),
+            None => {
+                let trailers = bsii.blocks_by_prototype_name("trailer").collect::<Vec<_>>();
+                let slaves = trailers
+                    .iter()
+                    .filter_map(|trailer| units.reference(trailer, "slave_trailer"))
+                    .map(|slave| &slave.id)
+                    .collect::<HashSet<_>>();
+                trailers
+                    .into_iter()
+                    .filter(|trailer| !slaves.contains(&trailer.id))
+                    .collect()
+            }
+        };
+        let slots = garage_slots(bsii, "trailers");
+        blocks
+            .into_iter()
+            .map(|block| Self::from_block(units, block, &slots, &mut HashSet::new()))
+            .collect()
+    }
+
+    /// `chain` holds the trailers read so far, so that a cyclic reference
+    /// between slave trailers ends the chain.
+    fn from_block<'data>(
+        units: &Units<'data, '_>,
+        block: &'data DataBlock<'_>,
+        slots: &HashMap<Id, GarageSlot>,
+        chain: &mut HashSet<&'data Id>,
+    ) -> Self {
+        let bsii = units.bsii;
+        chain.insert(&block.id);
+        let accessories = Accessory::all_of(units, block);
+        let definition = units.reference(block, "trailer_definition");
+        let slave_trailer = units
+            .reference(block, "slave_trailer")
+            .filter(|slave| !chain.contains(&slave.id))
+            .map(|slave| Box::new(Self::from_block(units, slave, slots, chain)));
+        Self {
+            id: block.id.to_string(),
+            model: Accessory::vehicle_of(&accessories).map(ToString::to_string),
+            body_type: definition.and_then(|definition| string(bsii, definition, "body_type")),
+            chain_type: definition.and_then(|definition| string(bsii, definition, "chain_type")),
+            chassis: Accessory::component_of(&accessories, "chassis"),
+            license_plate: string(bsii, block, "license_plate").unwrap_or_default(),
+            odometer: integer(bsii, block, "odometer").unwrap_or(0),
+            wear: Wear::from_block(bsii, block),
+            accessories,
+            garage: slots.get(&block.id).map(|slot| slot.garage.clone()),
+            slave_trailer,
+        }
+    }
+
+    /// The cargos that can be loaded on the trailer by its body type.
+    pub fn cargos(&self) -> Vec<&'static CargoMetadata> {
+        let Some(body_type) = self.body_type.as_deref() else {
+            return vec![];
+        };
+        CARGOS
+            .iter()
+            .filter(|cargo| cargo.body_types.contains(&body_type))
+            .collect()
+    }
+
+    /// The trailer categories of the cargos that can be loaded on the trailer.
+    pub fn trailer_categories(&self) -> BTreeSet<&'static str> {
+        self.cargos()
+            .into_iter()
+            .flat_map(|cargo| cargo.trailer_categories.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{token, unit_id, unit_ids, SaveBuilder};
+
+    fn trailer(
+        definition: &str,
+        accessories: &[&str],
+        slave_trailer: &str,
+        odometer: u32,
+    ) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("trailer_definition", DataValue::Id(unit_id(definition))),
+            ("slave_trailer", DataValue::Id(unit_id(slave_trailer))),
+            ("accessories", unit_ids(accessories)),
+            ("odometer", DataValue::UInt32(odometer)),
+            ("license_plate", DataValue::String("XY 987|germany")),
+            ("chassis_wear", DataValue::Float(0.5)),
+            ("body_wear", DataValue::Float(0.25)),
+            ("wheels_wear", DataValue::FloatArray(vec![0.0, 0.0])),
+        ]
+    }
+
+    fn trailer_def(body_type: &str, chain_type: &str) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![
+            ("body_type", token(body_type)),
+            ("chain_type", token(chain_type)),
+        ]
+    }
+
+    /// A double whose second trailer links back to the first one when
+    /// `cyclic`.
+    fn sample_bsii(player: bool, cyclic: bool) -> BsiiFile<'static> {
+        let builder = if player {
+            SaveBuilder::new().unit(
+                "player",
+                "player",
+                vec![("trailers", unit_ids(&["_nameless.300"]))],
+            )
+        } else {
+            SaveBuilder::new()
+        };
+        builder
+            .unit(
+                "trailer",
+                "_nameless.300",
+                trailer(
+                    "_nameless.310",
+                    &["_nameless.320", "_nameless.321"],
+                    "_nameless.400",
+                    5_000,
+                ),
+            )
+            .unit(
+                "trailer",
+                "_nameless.400",
+                trailer(
+                    "_nameless.410",
+                    &[],
+                    if cyclic { "_nameless.300" } else { "null" },
+                    4_000,
+                ),
+            )
+            .unit(
+                "trailer_def",
+                "_nameless.310",
+                trailer_def("dryvan", "double"),
+            )
+            .unit(
+                "trailer_def",
+                "_nameless.410",
+                trailer_def("dryvan", "double"),
+            )
+            .unit(
+                "vehicle_accessory",
+                "_nameless.320",
+                vec![(
+                    "data_path",
+                    DataValue::String("/def/vehicle/trailer_owned/scs.box/data.sii"),
+                )],
+            )
+            .unit(
+                "vehicle_accessory",
+                "_nameless.321",
+                vec![(
+                    "data_path",
+                    DataValue::String("/def/vehicle/trailer_owned/scs.box/chassis/double_3.sii"),
+                )],
+            )
+            .unit(
+                "garage",
+                "garage.berlin",
+                vec![("trailers", unit_ids(&["_nameless.300", "null"]))],
+            )
+            .build()
+    }
+
+    #[test]
+    fn reads_trailers_and_ends_cyclic_chains() {
+        let trailers = Trailer::all_from_bsii(&sample_bsii(true, true));
+
+        assert_eq!(trailers.len(), 1);
+        let trailer = &trailers[0];
+        assert_eq!(trailer.model.as_deref(), Some("scs.box"));
+        assert_eq!(trailer.body_type.as_deref(), Some("dryvan"));
+        assert_eq!(trailer.chain_type.as_deref(), Some("double"));
+        assert_eq!(trailer.chassis.as_deref(), Some("double_3"));
+        assert_eq!(trailer.license_plate, "XY 987|germany");
+        assert_eq!(trailer.odometer, 5_000);
+        assert_eq!(trailer.wear.chassis, Some(0.5));
+        assert_eq!(trailer.wear.body, Some(0.25));
+        assert_eq!(trailer.garage.as_deref(), Some("garage.berlin"));
+        let slave = trailer.slave_trailer.as_deref().unwrap();
+        assert_eq!(slave.id, "_nameless.400");
+        assert_eq!(slave.odometer, 4_000);
+        assert_eq!(slave.garage, None);
+        assert_eq!(slave.slave_trailer, None);
+    }
+
+    #[test]
+    fn skips_slave_trailers_without_player() {
+        let trailers = Trailer::all_from_bsii(&sample_bsii(false, false));
+
+        assert_eq!(trailers.len(), 1);
+        assert_eq!(trailers[0].id, "_nameless.300");
+        assert!(trailers[0].slave_trailer.is_some());
+    }
+
+    #[test]
+    fn joins_cargos_by_body_type() {
+        let trailer = &Trailer::all_from_bsii(&sample_bsii(true, false))[0];
+
+        let cargos = trailer.cargos();
+        assert!(cargos.iter().any(|cargo| cargo.id == "air_mails"));
+        assert!(cargos
+            .iter()
+            .all(|cargo| cargo.body_types.contains(&"dryvan")));
+        assert!(trailer.trailer_categories().contains("dryvan"));
+        assert!(!trailer.trailer_categories().contains("tr_tank"));
+    }
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id};
+use crate::ets2::fields::{float, integer, is_null, string, Units};
+
+/// The file name of the accessory that holds the vehicle data, e.g.
+/// `/def/vehicle/truck/scania.r/data.sii`.
+const VEHICLE_DATA_FILE: &str = "data.sii";
+
+/// An owned truck, read from a `vehicle` unit with its accessories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Truck {
+    pub id: String,
+    /// The brand and model, e.g. `scania` and `r`, from the vehicle data
+    /// accessory.
+    pub brand: Option<String>,
+    pub model: Option<String>,
+    pub engine: Option<String>,
+    pub transmission: Option<String>,
+    pub chassis: Option<String>,
+    pub cabin: Option<String>,
+    pub paint_job: Option<String>,
+    /// The license plate with its formatting tags and the country after `|`.
+    pub license_plate: String,
+    pub odometer: i64,
+    /// The fuel level relative to the tank capacity.
+    pub fuel_relative: Option<f64>,
+    pub wear: Wear,
+    pub accessories: Vec<Accessory>,
+    /// The garage the truck is parked at.
+    pub garage: Option<String>,
+    /// The driver assigned to the truck in its garage.
+    pub driver: Option<String>,
+}
+
+/// The wear of the vehicle components, from 0 for new to 1 for broken.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Wear {
+    pub engine: Option<f64>,
+    pub transmission: Option<f64>,
+    pub cabin: Option<f64>,
+    pub chassis: Option<f64>,
+    pub body: Option<f64>,
+    pub wheels: Vec<f64>,
+}
+
+/// An accessory of a vehicle, e.g. a `vehicle_accessory`,
+/// `vehicle_addon_accessory` or `vehicle_paint_job_accessory` unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accessory {
+    /// The class of the unit.
+    pub kind: String,
+    /// The definition of the accessory, e.g.
+    /// `/def/vehicle/truck/scania.r/engine/dc16_730.sii`.
+    pub data_path: String,
+}
+
+impl Accessory {
+    fn from_block(bsii: &BsiiFile<'_>, block: &DataBlock<'_>) -> Option<Self> {
+        Some(Self {
+            kind: block.prototype(bsii)?.name.to_string(),
+            data_path: string(bsii, block, "data_path").unwrap_or_default(),
+        })
+    }
+
+    /// The accessories referenced by the `accessories` field of a vehicle.
+    pub(crate) fn all_of(units: &Units<'_, '_>, block: &DataBlock<'_>) -> Vec<Self> {
+        units
+            .references(block, "accessories")
+            .into_iter()
+            .filter_map(|accessory| Self::from_block(units.bsii, accessory))
+            .collect()
+    }
+
+    /// The vehicle of the vehicle data accessory, e.g. `scania.r`.
+    pub(crate) fn vehicle_of(accessories: &[Self]) -> Option<&str> {
+        accessories
+            .iter()
+            .find(|accessory| accessory.is_vehicle_data())
+            .and_then(Accessory::vehicle)
+    }
+
+    /// The name of the accessory of a component, e.g. `dc16_730` for
+    /// `engine`.
+    pub(crate) fn component_of(accessories: &[Self], component: &str) -> Option<String> {
+        accessories
+            .iter()
+            .find(|accessory| accessory.component() == Some(component))
+            .map(|accessory| accessory.name().to_string())
+    }
+
+    /// The vehicle the accessory belongs to, e.g. `scania.r`.
+    pub fn vehicle(&self) -> Option<&str> {
+        self.path_parts().get(3).copied()
+    }
+
+    /// The component the accessory is part of, e.g. `engine` or `paint_job`.
+    /// The vehicle data has no component.
+    pub fn component(&self) -> Option<&str> {
+        let parts = self.path_parts();
+        (parts.len() > 5).then(|| parts[4])
+    }
+
+    /// The name of the definition file without extension, e.g. `dc16_730`.
+    pub fn name(&self) -> &str {
+        let file = self.data_path.rsplit('/').next().unwrap_or_default();
+        file.strip_suffix(".sii").unwrap_or(file)
+    }
+
+    /// The parts of `/def/vehicle/<kind>/<vehicle>/<component>/<name>.sii`.
+    fn path_parts(&self) -> Vec<&str> {
+        self.data_path.trim_start_matches('/').split('/').collect()
+    }
+
+    fn is_vehicle_data(&self) -> bool {
+        self.data_path.ends_with(VEHICLE_DATA_FILE) && self.path_parts().len() == 5
+    }
+}
+
+impl Wear {
+    pub(crate) fn from_block(bsii: &BsiiFile<'_>, block: &DataBlock<'_>) -> Self {
+        let wheels = match block.field(bsii, "wheels_wear") {
+            Some(DataValue::FloatArray(wear)) => wear.iter().map(|wear| f64::from(*wear)).collect(),
+            _ => vec![],
+        };
+        Self {
+            engine: float(bsii, block, "engine_wear"),
+            transmission: float(bsii, block, "transmission_wear"),
+            cabin: float(bsii, block, "cabin_wear"),
+            chassis: float(bsii, block, "chassis_wear"),
+            body: float(bsii, block, "body_wear"),
+            wheels,
+        }
+    }
+}
+
+impl Truck {
+    /// Read the trucks owned by the player, or all vehicles if the save has
+    /// no `player` unit.
+    pub fn all_from_bsii(bsii: &BsiiFile<'_>) -> Vec<Self> {
+        Self::all_from_units(&Units::new(bsii))
+    }
+
+    pub(crate) fn all_from_units(units: &Units<'_, '_>) -> Vec<Self> {
+        let bsii = units.bsii;
+        let blocks = match bsii.blocks_by_prototype_name("player").next() {
+            Some(player) => units.references(player, "trucks"),
+            None => bsii.blocks_by_prototype_name("vehicle").collect(),
+        };
+        let slots = garage_slots(bsii, "vehicles");
+        blocks
+            .into_iter()
+            .map(|block| Self::from_block(units, block, &slots))
+            .collect()
+    }
+
+    fn from_block(
+        units: &Units<'_, '_>,
+        block: &DataBlock<'_>,
+        slots: &HashMap<Id, GarageSlot>,
+    ) -> Self {
+        let bsii = units.bsii;
+        let accessories = Accessory::all_of(units, block);
+        let vehicle = Accessory::vehicle_of(&accessories);
+        let (brand, model) = match vehicle.map(|vehicle| vehicle.split_once('.')) {
+            Some(Some((brand, model))) => (Some(brand.to_string()), Some(model.to_string())),
+            Some(None) => (vehicle.map(ToString::to_string), None),
+            None => (None, None),
+        };
+        let component = |name| Accessory::component_of(&accessories, name);
+        let slot = slots.get(&block.id);
+        Self {
+            id: block.id.to_string(),
+            brand,
+            model,
+            engine: component("engine"),
+            transmission: component("transmission"),
+            chassis: component("chassis"),
+            cabin: component("cabin"),
+            paint_job: component("paint_job"),
+            license_plate: string(bsii, block, "license_plate").unwrap_or_default(),
+            odometer: integer(bsii, block, "odometer").unwrap_or(0),
+            fuel_relative: float(bsii, block, "fuel_relative"),
+            wear: Wear::from_block(bsii, block),
+            accessories,
+            garage: slot.map(|slot| slot.garage.clone()),
+            driver: slot.and_then(|slot| slot.driver.clone()),
+        }
+    }
+}
+
+/// The garage of a vehicle and, for trucks, the driver in the same slot.
+pub(crate) struct GarageSlot {
+    pub(crate) garage: String,
+    pub(crate) driver: Option<String>,
+}
+
+/// Map the vehicles or trailers of all garages to their slot.
+pub(crate) fn garage_slots(bsii: &BsiiFile<'_>, field: &str) -> HashMap<Id, GarageSlot> {
+    let mut slots = HashMap::new();
+    for garage in bsii.blocks_by_prototype_name("garage") {
+        let Some(DataValue::IdArray(vehicles)) = garage.field(bsii, field) else {
+            continue;
+        };
+        let drivers = match garage.field(bsii, "drivers") {
+            Some(DataValue::IdArray(drivers)) => drivers.as_slice(),
+            _ => &[],
+        };
+        for (index, vehicle) in vehicles.iter().enumerate() {
+            if is_null(vehicle) {
+                continue;
+            }
+            let driver = drivers.get(index).filter(|driver| !is_null(driver));
+            slots.insert(
+                vehicle.clone(),
+                GarageSlot {
+                    garage: garage.id.to_string(),
+                    driver: driver.map(ToString::to_string),
+                },
+            );
+        }
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ets2::test_support::{unit_ids, SaveBuilder};
+
+    fn accessory(data_path: &'static str) -> Vec<(&'static str, DataValue<'static>)> {
+        vec![("data_path", DataValue::String(data_path))]
+    }
+
+    #[test]
+    fn reads_trucks_with_accessories() {
+        let bsii = SaveBuilder::new()
+            .unit(
+                "player",
+                "player",
+                vec![("trucks", unit_ids(&["_nameless.100", "_nameless.200"]))],
+            )
+            .unit(
+                "vehicle",
+                "_nameless.100",
+                vec![
+                    (
+                        "accessories",
+                        unit_ids(&[
+                            "_nameless.101",
+                            "_nameless.102",
+                            "_nameless.103",
+                            "_nameless.104",
+                        ]),
+                    ),
+                    ("engine_wear", DataValue::Float(0.25)),
+                    ("chassis_wear", DataValue::Float(0.5)),
+                    ("wheels_wear", DataValue::FloatArray(vec![0.0, 0.125])),
+                    ("odometer", DataValue::UInt32(123_456)),
+                    ("fuel_relative", DataValue::Float(0.75)),
+                    ("license_plate", DataValue::String("AB 123|germany")),
+                ],
+            )
+            .unit(
+                "vehicle_accessory",
+                "_nameless.101",
+                accessory("/def/vehicle/truck/scania.r/data.sii"),
+            )
+            .unit(
+                "vehicle_accessory",
+                "_nameless.102",
+                accessory("/def/vehicle/truck/scania.r/engine/dc16_730.sii"),
+            )
+            .unit(
+                "vehicle_paint_job_accessory",
+                "_nameless.103",
+                accessory("/def/vehicle/truck/scania.r/paint_job/default.sii"),
+            )
+            .unit(
+                "vehicle_addon_accessory",
+                "_nameless.104",
+                accessory("/def/vehicle/truck/scania.r/accessory/horn/air.sii"),
+            )
+            .unit(
+                "vehicle",
+                "_nameless.200",
+                vec![
+                    ("accessories", unit_ids(&[])),
+                    ("engine_wear", DataValue::Float(0.0)),
+                    ("chassis_wear", DataValue::Float(0.0)),
+                    ("wheels_wear", DataValue::FloatArray(vec![])),
+                    ("odometer", DataValue::UInt32(0)),
+                    ("fuel_relative", DataValue::Float(1.0)),
+                    ("license_plate", DataValue::String("")),
+                ],
+            )
+            .unit(
+                "garage",
+                "garage.berlin",
+                vec![
+                    ("vehicles", unit_ids(&["null", "_nameless.100"])),
+                    ("drivers", unit_ids(&["null", "driver.hans"])),
+                ],
+            )
+            .build();
+
+        let trucks = Truck::all_from_bsii(&bsii);
+
+        assert_eq!(trucks.len(), 2);
+        let truck = &trucks[0];
+        assert_eq!(truck.id, "_nameless.100");
+        assert_eq!(
+            (truck.brand.as_deref(), truck.model.as_deref()),
+            (Some("scania"), Some("r"))
+        );
+        assert_eq!(truck.engine.as_deref(), Some("dc16_730"));
+        assert_eq!(truck.paint_job.as_deref(), Some("default"));
+        assert_eq!(truck.transmission, None);
+        assert_eq!(truck.accessories.len(), 4);
+        assert_eq!(truck.accessories[3].kind, "vehicle_addon_accessory");
+        assert_eq!(truck.accessories[3].component(), Some("accessory"));
+        assert_eq!(truck.license_plate, "AB 123|germany");
+        assert_eq!(truck.odometer, 123_456);
+        assert_eq!(truck.fuel_relative, Some(0.75));
+        assert_eq!(truck.wear.engine, Some(0.25));
+        assert_eq!(truck.wear.cabin, None);
+        assert_eq!(truck.wear.wheels, [0.0, 0.125]);
+        assert_eq!(truck.garage.as_deref(), Some("garage.berlin"));
+        assert_eq!(truck.driver.as_deref(), Some("driver.hans"));
+        assert_eq!(trucks[1].brand, None);
+        assert_eq!(trucks[1].garage, None);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::bsii_file::BsiiFile;
+use crate::ets2::fields::{boolean, integer, string, strings};
+use crate::json::{json_escape, render_string_iter};
+
+/// A player profile, read from the `user_profile` unit of `profile.sii`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub profile_name: String,
+    pub company_name: String,
+    /// The truck brand chosen for the company, e.g. `scania`.
+    pub brand: String,
+    pub logo: String,
+    pub face: Option<i64>,
+    pub male: Option<bool>,
+    pub map_path: String,
+    pub cached_experience: Option<i64>,
+    pub cached_distance: Option<i64>,
+    pub online_user_name: String,
+    /// Active mods in load order, with the highest priority first.
+    pub active_mods: Vec<ActiveMod>,
+    pub user_data: Vec<String>,
+    pub version: Option<i64>,
+    /// The time the profile was created as a Unix timestamp.
+    pub creation_time: Option<i64>,
+    /// The time the profile was last saved as a Unix timestamp.
+    pub save_time: Option<i64>,
+}
+
+/// An active mod, written as `id|name`, e.g. `promods-def-v270|ProMods Definition`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveMod {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ProfileError {
+    MissingUserProfile,
+}
+
+impl std::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileError::MissingUserProfile => write!(f, "missing user_profile block"),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+impl Profile {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Result<Self, ProfileError> {
+        let block = bsii
+            .blocks_by_prototype_name("user_profile")
+            .next()
+            .ok_or(ProfileError::MissingUserProfile)?;
+        let text = |name| string(bsii, block, name).unwrap_or_default();
+        Ok(Self {
+            profile_name: text("profile_name"),
+            company_name: text("company_name"),
+            brand: text("brand"),
+            logo: text("logo"),
+            face: integer(bsii, block, "face"),
+            male: boolean(bsii, block, "male"),
+            map_path: text("map_path"),
+            cached_experience: integer(bsii, block, "cached_experience"),
+            cached_distance: integer(bsii, block, "cached_distance"),
+            online_user_name: text("online_user_name"),
+            active_mods: strings(bsii, block, "active_mods")
+                .iter()
+                .map(|active_mod| ActiveMod::parse(active_mod))
+                .collect(),
+            user_data: strings(bsii, block, "user_data"),
+            version: integer(bsii, block, "version"),
+            creation_time: integer(bsii, block, "creation_time"),
+            save_time: integer(bsii, block, "save_time"),
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        let strings = [
+            ("profile_name", &self.profile_name),
+            ("company_name", &self.company_name),
+            ("brand", &self.brand),
+            ("logo", &self.logo),
+            ("map_path", &self.map_path),
+            ("online_user_name", &self.online_user_name),
+        ];
+        let numbers = [
+            ("face", self.face),
+            ("cached_experience", self.cached_experience),
+            ("cached_distance", self.cached_distance),
+            ("version", self.version),
+            ("creation_time", self.creation_time),
+            ("save_time", self.save_time),
+        ];
+        let mut fields = strings
+            .iter()
+            .map(|(name, value)| format!("  \"{name}\": \"{}\"", json_escape(value)))
+            .collect::<Vec<_>>();
+        fields.extend(
+            numbers
+                .iter()
+                .map(|(name, value)| format!("  \"{name}\": {}", render_optional(value.as_ref()))),
+        );
+        fields.push(format!(
+            "  \"male\": {}",
+            render_optional(self.male.as_ref())
+        ));
+        let active_mods = self
+            .active_mods
+            .iter()
+            .map(|active_mod| {
+                format!(
+                    "{{ \"id\": \"{}\", \"name\": \"{}\" }}",
+                    json_escape(&active_mod.id),
+                    json_escape(&active_mod.name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        fields.push(format!("  \"active_mods\": [{active_mods}]"));
+        fields.push(format!(
+            "  \"user_data\": {}",
+            render_string_iter(self.user_data.iter().map(String::as_str))
+        ));
+        format!("{{\n{}\n}}\n", fields.join(",\n"))
+    }
+}
+
+impl ActiveMod {
+    /// Parse an active mod. Values without a name use the ID as name.
+    pub fn parse(value: &str) -> Self {
+        let (id, name) = value.split_once('|').unwrap_or((value, value));
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+fn render_optional<T: std::fmt::Display>(value: Option<&T>) -> String {
+    value.map_or_else(|| "null".to_string(), ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bsii_file::DataValue;
+    use crate::ets2::test_support::{token, SaveBuilder};
+
+    fn sample() -> BsiiFile<'static> {
+        SaveBuilder::new()
+            .unit(
+                "user_profile",
+                "_nameless.1234",
+                vec![
+                    ("face", DataValue::UInt32(3)),
+                    ("brand", token("scania")),
+                    ("map_path", DataValue::String("/map/europe.mbd")),
+                    ("logo", token("logo_01")),
+                    ("company_name", DataValue::String("Trans \"Euro\"")),
+                    ("male", DataValue::Bool(true)),
+                    ("cached_experience", DataValue::UInt32(120_500)),
+                    ("cached_distance", DataValue::UInt32(u32::MAX)),
+                    ("user_data", DataValue::StringArray(vec!["0", "1"])),
+                    (
+                        "active_mods",
+                        DataValue::StringArray(vec![
+                            "promods-def-v270|ProMods Definition",
+                            "promods",
+                        ]),
+                    ),
+                    ("profile_name", DataValue::String("John")),
+                    ("creation_time", DataValue::UInt32(1_700_000_000)),
+                ],
+            )
+            .build()
+    }
+
+    #[test]
+    fn reads_user_profile() {
+        let profile = Profile::from_bsii(&sample()).unwrap();
+
+        assert_eq!(profile.profile_name, "John");
+        assert_eq!(profile.company_name, "Trans \"Euro\"");
+        assert_eq!(
+            (profile.brand.as_str(), profile.logo.as_str()),
+            ("scania", "logo_01")
+        );
+        assert_eq!(profile.face, Some(3));
+        assert_eq!(profile.male, Some(true));
+        assert_eq!(profile.cached_experience, Some(120_500));
+        assert_eq!(profile.cached_distance, None);
+        assert_eq!(profile.online_user_name, "");
+        assert_eq!(
+            profile.active_mods,
+            [
+                ActiveMod {
+                    id: "promods-def-v270".to_string(),
+                    name: "ProMods Definition".to_string(),
+                },
+                ActiveMod {
+                    id: "promods".to_string(),
+                    name: "promods".to_string(),
+                },
+            ]
+        );
+        assert_eq!(profile.creation_time, Some(1_700_000_000));
+        assert_eq!(profile.save_time, None);
+    }
+
+    #[test]
+    fn renders_profile_json() {
+        let json = Profile::from_bsii(&sample()).unwrap().to_json();
+
+        assert!(json.contains("  \"company_name\": \"Trans \\\"Euro\\\"\",\n"));
+        assert!(json.contains("  \"cached_distance\": null,\n"));
+        assert!(json.contains("  \"male\": true,\n"));
+        assert!(json.contains(
+            "  \"active_mods\": [{ \"id\": \"promods-def-v270\", \"name\": \"ProMods Definition\" }, { \"id\": \"promods\", \"name\": \"promods\" }],\n"
+        ));
+        assert!(json.ends_with("  \"user_data\": [\"0\", \"1\"]\n}\n"));
+    }
+}
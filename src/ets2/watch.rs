@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::ets2::profile::{GAME_FILE, SAVE_DIR};
+
+/// Watches the `save/` directory of a profile for new or modified `game.sii`
+/// files.
+///
+/// The game writes saves in place, so a file is only reported once its size
+/// and modification time are unchanged between two consecutive polls. Callers
+/// are expected to call `poll` periodically, with an interval long enough for
+/// the game to make progress writing a save.
+#[derive(Debug)]
+pub struct SaveWatcher {
+    save_dir: PathBuf,
+    /// The state of each save when it was last seen.
+    seen: HashMap<PathBuf, FileState>,
+    /// The state of each save when it was last reported.
+    reported: HashMap<PathBuf, FileState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl SaveWatcher {
+    /// Start watching the profile directory. Saves that already exist are
+    /// not reported until they are modified.
+    pub fn new(profile_dir: impl AsRef<Path>) -> io::Result<Self> {
+        let save_dir = profile_dir.as_ref().join(SAVE_DIR);
+        let existing = scan(&save_dir)?;
+        Ok(Self {
+            save_dir,
+            seen: existing.clone(),
+            reported: existing,
+        })
+    }
+
+    /// The directory containing the save slots.
+    pub fn save_dir(&self) -> &Path {
+        &self.save_dir
+    }
+
+    /// The most recently modified save that has been reported or existed when
+    /// the watcher was created.
+    pub fn latest(&self) -> Option<&Path> {
+        self.reported
+            .iter()
+            .max_by_key(|(_, state)| state.modified)
+            .map(|(path, _)| path.as_path())
+    }
+
+    /// Check the save directory, returning the saves that were created or
+    /// modified and have finished writing since the last poll, oldest first.
+    pub fn poll(&mut self) -> io::Result<Vec<PathBuf>> {
+        let current = scan(&self.save_dir)?;
+        let mut changed = current
+            .iter()
+            .filter(|(path, state)| {
+                self.seen.get(*path) == Some(*state) && self.reported.get(*path) != Some(*state)
+            })
+            .map(|(path, state)| (path.clone(), *state))
+            .collect::<Vec<_>>();
+        changed.sort_by_key(|(path, state)| (state.modified, path.clone()));
+        for (path, state) in &changed {
+            self.reported.insert(path.clone(), *state);
+        }
+        self.reported.retain(|path, _| current.contains_key(path));
+        self.seen = current;
+        Ok(changed.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+/// Find the `game.sii` file of each save slot in the directory.
+fn scan(save_dir: &Path) -> io::Result<HashMap<PathBuf, FileState>> {
+    let mut saves = HashMap::new();
+    let entries = match fs::read_dir(save_dir) {
+        Ok(entries) => entries,
+        // The directory is created with the first save
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(saves),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let path = entry?.path().join(GAME_FILE);
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_file() {
+            let state = FileState {
+                len: metadata.len(),
+                modified: metadata.modified().ok(),
+            };
+            saves.insert(path, state);
+        }
+    }
+    Ok(saves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_saves_once_they_stop_changing() {
+        let profile_dir =
+            std::env::temp_dir().join(format!("sii-decode-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&profile_dir);
+        let slot = profile_dir.join("save").join("autosave");
+        fs::create_dir_all(profile_dir.join("save").join("1")).unwrap();
+        fs::write(profile_dir.join("save").join("1").join("game.sii"), "old").unwrap();
+
+        let mut watcher = SaveWatcher::new(&profile_dir).unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+        assert!(watcher.latest().unwrap().ends_with("1/game.sii"));
+
+        fs::create_dir_all(&slot).unwrap();
+        fs::write(slot.join("game.sii"), "partial").unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+        fs::write(slot.join("game.sii"), "partial and done").unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+        assert_eq!(watcher.poll().unwrap(), [slot.join("game.sii")]);
+        assert!(watcher.poll().unwrap().is_empty());
+
+        fs::write(slot.join("game.sii"), "next save").unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+        assert_eq!(watcher.poll().unwrap(), [slot.join("game.sii")]);
+
+        fs::remove_dir_all(&profile_dir).unwrap();
+    }
+}
@@ -5,11 +5,14 @@ use std::borrow::Cow;
 use log::info;
 
 use crate::bsii_parse;
+use crate::bsii_write;
 use crate::scsc_file;
+use crate::siin_parse::{self, SiinDocument};
+use crate::three_nk;
 use crate::{bsii_file::BsiiFile, scsc_file::ScscFile};
 
 /// FileType enum representing different file types.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileType {
     /// A binary file containing compressed and encrypted data.
     Scsc,
@@ -17,8 +20,13 @@ pub enum FileType {
     Bsii,
     /// A text file of SII data format.
     Siin,
+    /// A binary file containing scrambled data.
+    ThreeNK,
 }
 
+/// The seed used when encoding 3nK files.
+const THREE_NK_SEED: u8 = 0;
+
 /// Detects the file type based on the header of the file.
 /// If the file type is not recognized, it returns None.
 pub fn detect_file_type(file_content: &[u8]) -> Option<FileType> {
@@ -31,6 +39,8 @@ pub fn detect_file_type(file_content: &[u8]) -> Option<FileType> {
         Some(FileType::Bsii)
     } else if file_content[0..4] == *"SiiN".as_bytes() {
         Some(FileType::Siin)
+    } else if file_content[0..4] == *three_nk::SIGNATURE {
+        Some(FileType::ThreeNK)
     } else {
         None
     }
@@ -46,6 +56,12 @@ pub enum DecodeError {
     ScscDecode(scsc_file::DecodeError),
     /// Error when BSII file parsing fails.
     BsiiParse(bsii_parse::ParseError),
+    /// Error when textual SII parsing fails.
+    SiinParse(siin_parse::ParseError),
+    /// Error when 3nK file parsing fails.
+    ThreeNKParse(three_nk::ParseError),
+    /// Error when a BSII file cannot be written.
+    BsiiWrite(bsii_write::WriteError),
     /// Error when structured analysis is requested for textual SII.
     StructuredBsiiUnavailable,
 }
@@ -68,6 +84,24 @@ impl From<bsii_parse::ParseError> for DecodeError {
     }
 }
 
+impl From<siin_parse::ParseError> for DecodeError {
+    fn from(err: siin_parse::ParseError) -> Self {
+        DecodeError::SiinParse(err)
+    }
+}
+
+impl From<three_nk::ParseError> for DecodeError {
+    fn from(err: three_nk::ParseError) -> Self {
+        DecodeError::ThreeNKParse(err)
+    }
+}
+
+impl From<bsii_write::WriteError> for DecodeError {
+    fn from(err: bsii_write::WriteError) -> Self {
+        DecodeError::BsiiWrite(err)
+    }
+}
+
 impl std::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -75,6 +109,9 @@ impl std::fmt::Display for DecodeError {
             DecodeError::ScscParse(err) => write!(f, "Scsc parse error: {}", err),
             DecodeError::ScscDecode(err) => write!(f, "Scsc decode error: {}", err),
             DecodeError::BsiiParse(err) => write!(f, "BSII parse error: {}", err),
+            DecodeError::SiinParse(err) => write!(f, "SiiN parse error: {}", err),
+            DecodeError::ThreeNKParse(err) => write!(f, "3nK parse error: {}", err),
+            DecodeError::BsiiWrite(err) => write!(f, "BSII write error: {}", err),
             DecodeError::StructuredBsiiUnavailable => {
                 write!(f, "Structured BSII analysis requires a binary BSII file")
             }
@@ -100,7 +137,7 @@ pub fn decode_until_bsii(file_content: &[u8]) -> Result<Cow<'_, [u8]>, DecodeErr
                     Ok(Cow::Owned(decoded_content))
                 }
                 FileType::Siin => Err(DecodeError::StructuredBsiiUnavailable),
-                FileType::Scsc => unreachable!("Unexpected ScsC file after decoding"),
+                FileType::Scsc | FileType::ThreeNK => Err(DecodeError::UnknownFileType),
             }
         }
         FileType::Bsii => {
@@ -108,6 +145,58 @@ pub fn decode_until_bsii(file_content: &[u8]) -> Result<Cow<'_, [u8]>, DecodeErr
             Ok(Cow::Borrowed(file_content))
         }
         FileType::Siin => Err(DecodeError::StructuredBsiiUnavailable),
+        FileType::ThreeNK => {
+            let decoded_content = three_nk::decode(file_content)?;
+            match detect_file_type(&decoded_content).ok_or(DecodeError::UnknownFileType)? {
+                FileType::Bsii => {
+                    BsiiFile::parse(&decoded_content)?;
+                    Ok(Cow::Owned(decoded_content))
+                }
+                FileType::Siin => Err(DecodeError::StructuredBsiiUnavailable),
+                _ => Err(DecodeError::UnknownFileType),
+            }
+        }
+    }
+}
+
+/// Encode a BSII file into the given file type.
+///
+/// ScsC files are produced by encrypting the binary BSII representation.
+pub fn encode_bsii(bsii: &BsiiFile<'_>, file_type: FileType) -> Result<Vec<u8>, DecodeError> {
+    Ok(match file_type {
+        FileType::Scsc => scsc_file::encode(&bsii.to_bytes()?),
+        FileType::Bsii => bsii.to_bytes()?,
+        FileType::Siin => bsii.to_siin().into_bytes(),
+        FileType::ThreeNK => three_nk::encode(bsii.to_siin().as_bytes(), THREE_NK_SEED),
+    })
+}
+
+/// Convert a supported file into the given file type.
+///
+/// Textual `SiiN` input has no prototypes, so converting it to BSII infers the
+/// field types from the values. Converting a file to the type it already has
+/// returns it unchanged, and ScsC output wraps the decoded payload as is.
+/// 3nK output always scrambles textual SII.
+pub fn convert(file_content: &[u8], target: FileType) -> Result<Vec<u8>, DecodeError> {
+    let file_type = detect_file_type(file_content).ok_or(DecodeError::UnknownFileType)?;
+    info!("Converting {:?} to {:?}", file_type, target);
+    if file_type == target {
+        return Ok(file_content.to_vec());
+    }
+    let payload = match file_type {
+        FileType::Scsc => Cow::Owned(ScscFile::parse(file_content)?.decode()?),
+        FileType::ThreeNK => Cow::Owned(three_nk::decode(file_content)?),
+        FileType::Bsii | FileType::Siin => Cow::Borrowed(file_content),
+    };
+    match detect_file_type(&payload).ok_or(DecodeError::UnknownFileType)? {
+        FileType::Bsii => encode_bsii(&BsiiFile::parse(&payload)?, target),
+        FileType::Siin => match target {
+            FileType::Siin => Ok(payload.into_owned()),
+            FileType::Scsc => Ok(scsc_file::encode(&payload)),
+            FileType::Bsii => Ok(SiinDocument::parse(&payload)?.to_bsii()?.to_bytes()?),
+            FileType::ThreeNK => Ok(three_nk::encode(&payload, THREE_NK_SEED)),
+        },
+        FileType::Scsc | FileType::ThreeNK => Err(DecodeError::UnknownFileType),
     }
 }
 
@@ -126,7 +215,7 @@ pub fn decode_until_siin(file_content: &[u8]) -> Result<Vec<u8>, DecodeError> {
                     let bsii_file = BsiiFile::parse(&decoded_content)?;
                     Ok(bsii_file.to_siin().as_bytes().to_vec())
                 }
-                _ => unreachable!("Unexpected file type after decoding"),
+                _ => Err(DecodeError::UnknownFileType),
             }
         }
         FileType::Bsii => {
@@ -134,6 +223,17 @@ pub fn decode_until_siin(file_content: &[u8]) -> Result<Vec<u8>, DecodeError> {
             Ok(bsii_file.to_siin().into())
         }
         FileType::Siin => Ok(content.to_vec()),
+        FileType::ThreeNK => {
+            let decoded_content = three_nk::decode(content)?;
+            match detect_file_type(&decoded_content).ok_or(DecodeError::UnknownFileType)? {
+                FileType::Siin => Ok(decoded_content),
+                FileType::Bsii => {
+                    let bsii_file = BsiiFile::parse(&decoded_content)?;
+                    Ok(bsii_file.to_siin().into())
+                }
+                _ => Err(DecodeError::UnknownFileType),
+            }
+        }
     }
 }
 
@@ -156,11 +256,13 @@ mod tests {
         let bsii_header = b"BSII";
         let siin_header = b"SiiN";
         let empty_file = b"";
+        let three_nk_header = b"3nK\x01";
         let other_header = b"Other";
 
         assert_eq!(detect_file_type(scsc_header), Some(FileType::Scsc));
         assert_eq!(detect_file_type(bsii_header), Some(FileType::Bsii));
         assert_eq!(detect_file_type(siin_header), Some(FileType::Siin));
+        assert_eq!(detect_file_type(three_nk_header), Some(FileType::ThreeNK));
         assert_eq!(detect_file_type(empty_file), None);
         assert_eq!(detect_file_type(other_header), None);
     }
@@ -198,4 +300,64 @@ mod tests {
             "SiiNunit\n{\n}\n"
         );
     }
+
+    #[test]
+    fn convert_round_trips_between_file_types() {
+        let siin = b"SiiNunit\n{\nplayer : player.one {\n  name: \"Driver\"\n  level: 3\n}\n}\n";
+
+        let bsii = convert(siin, FileType::Bsii).unwrap();
+        assert_eq!(detect_file_type(&bsii), Some(FileType::Bsii));
+        let scsc = convert(&bsii, FileType::Scsc).unwrap();
+        assert_eq!(detect_file_type(&scsc), Some(FileType::Scsc));
+        assert_eq!(convert(&scsc, FileType::Bsii).unwrap(), bsii);
+        assert_eq!(
+            String::from_utf8(convert(&scsc, FileType::Siin).unwrap()).unwrap(),
+            "SiiNunit\n{\nplayer : player.one {\n  name: Driver\n  level: 3\n}\n}\n"
+        );
+    }
+
+    #[test]
+    fn convert_encrypts_textual_files_without_inference() {
+        let siin = b"SiiNunit\n{\n}\n";
+
+        let scsc = convert(siin, FileType::Scsc).unwrap();
+
+        assert_eq!(decode_until_siin(&scsc).unwrap(), siin);
+    }
+
+    #[test]
+    fn decodes_scrambled_files() {
+        let siin = b"SiiNunit\n{\n}\n";
+        let scrambled = three_nk::encode(siin, 0x42);
+
+        assert_eq!(detect_file_type(&scrambled), Some(FileType::ThreeNK));
+        assert_eq!(decode_until_siin(&scrambled).unwrap(), siin);
+        assert!(matches!(
+            decode_until_bsii(&scrambled),
+            Err(DecodeError::StructuredBsiiUnavailable)
+        ));
+        assert_eq!(convert(&scrambled, FileType::Siin).unwrap(), siin);
+        assert_eq!(
+            decode_until_siin(&convert(minimal_bsii(), FileType::ThreeNK).unwrap()).unwrap(),
+            siin
+        );
+    }
+
+    #[test]
+    fn rejects_nested_containers_in_scsc_files() {
+        let siin = b"SiiNunit\n{\n}\n";
+
+        for payload in [three_nk::encode(siin, 0x42), scsc_file::encode(siin)] {
+            let nested = scsc_file::encode(&payload);
+
+            assert!(matches!(
+                decode_until_siin(&nested),
+                Err(DecodeError::UnknownFileType)
+            ));
+            assert!(matches!(
+                decode_until_bsii(&nested),
+                Err(DecodeError::UnknownFileType)
+            ));
+        }
+    }
 }
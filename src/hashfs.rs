@@ -0,0 +1,786 @@
+//! Reads SCS HashFS archives (`.scs`), which hold the game definitions.
+//!
+//! Entries are looked up by the CityHash64 of their path, without a leading
+//! slash, so that the root directory is the hash of the empty string. Both
+//! versions of the format are supported:
+//!
+//! - Version 1 stores a table of 32 byte entries with their offset and sizes.
+//!   Directory listings are text with one name per line, where
+//!   subdirectories start with `*`.
+//! - Version 2 (game version 1.50 and later) stores a zlib compressed table of
+//!   16 byte entries, which point into a zlib compressed table of metadata
+//!   holding the offset and sizes. Directory listings start with the number
+//!   of names, followed by the length of every name and the names, where
+//!   subdirectories start with `/`.
+//!
+//! Only plain entries are supported. The packed textures of version 2 are
+//! reported as [`HashFsError::UnsupportedEntry`].
+//!
+//! References:
+//! <https://github.com/sk-zk/TruckLib/tree/master/TruckLib.HashFs>
+//! <https://modding.scssoft.com/wiki/Documentation/Tools/Game_Archive_Packer>
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+
+use flate2::read::ZlibDecoder;
+use nom::bytes::complete::{tag, take};
+use nom::number::complete::{le_u16, le_u32, le_u64};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use crate::cityhash::city_hash_64;
+
+const MAGIC: &[u8] = b"SCS#";
+const HASH_METHOD: &[u8] = b"CITY";
+const HEADER_SIZE: usize = 12;
+const V1_HEADER_SIZE: usize = 20;
+const V1_ENTRY_SIZE: usize = 32;
+/// The size of the v2 header fields that are read. The header on disk
+/// continues with fields that are not needed here.
+const V2_HEADER_SIZE: usize = 44;
+const V2_ENTRY_SIZE: usize = 16;
+
+const V1_FLAG_DIRECTORY: u32 = 0x1;
+const V1_FLAG_COMPRESSED: u32 = 0x2;
+const V2_FLAG_DIRECTORY: u16 = 0x1;
+const V2_METADATA_PLAIN: u8 = 0x80;
+const V2_METADATA_DIRECTORY: u8 = 0x81;
+const V2_OFFSET_BLOCK_SIZE: u64 = 16;
+
+/// An opened archive, with its entry table read into memory.
+#[derive(Debug)]
+pub struct HashFs<R> {
+    reader: R,
+    version: u16,
+    salt: u16,
+    entries: HashMap<u64, Entry>,
+}
+
+/// The location of an entry in the archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub hash: u64,
+    pub offset: u64,
+    pub size: u32,
+    pub compressed_size: u32,
+    pub compression: Compression,
+    pub is_directory: bool,
+    /// Whether the entry can be read, which is not the case for the packed
+    /// textures of version 2.
+    pub is_supported: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Zlib,
+}
+
+/// The names in a directory, without the prefixes of subdirectories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirListing {
+    pub directories: Vec<String>,
+    pub files: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum HashFsError {
+    Io(io::Error),
+    InvalidHeader,
+    UnsupportedVersion(u16),
+    UnsupportedHashMethod,
+    InvalidEntryTable,
+    UnsupportedCompression(u32),
+    UnsupportedEntry(String),
+    NotFound(String),
+    NotADirectory(String),
+    InvalidDirectory(String),
+    InvalidPath(String),
+}
+
+impl std::fmt::Display for HashFsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashFsError::Io(err) => write!(f, "IO error: {}", err),
+            HashFsError::InvalidHeader => write!(f, "Invalid header"),
+            HashFsError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported HashFS version {}", version)
+            }
+            HashFsError::UnsupportedHashMethod => write!(f, "Unsupported hash method"),
+            HashFsError::InvalidEntryTable => write!(f, "Invalid entry table"),
+            HashFsError::UnsupportedCompression(compression) => {
+                write!(f, "Unsupported compression {}", compression)
+            }
+            HashFsError::UnsupportedEntry(path) => write!(f, "{}: unsupported entry", path),
+            HashFsError::NotFound(path) => write!(f, "{}: not found", path),
+            HashFsError::NotADirectory(path) => write!(f, "{}: not a directory", path),
+            HashFsError::InvalidDirectory(path) => write!(f, "{}: invalid directory", path),
+            HashFsError::InvalidPath(path) => write!(f, "{}: invalid path", path),
+        }
+    }
+}
+
+impl std::error::Error for HashFsError {}
+
+impl From<io::Error> for HashFsError {
+    fn from(err: io::Error) -> Self {
+        HashFsError::Io(err)
+    }
+}
+
+/// Hash a path the way entries are looked up, ignoring leading and trailing
+/// slashes. A non-zero salt is prepended to the path in decimal.
+pub fn hash_path(path: &str, salt: u16) -> u64 {
+    let path = normalize(path);
+    if salt == 0 {
+        city_hash_64(path.as_bytes())
+    } else {
+        city_hash_64(format!("{salt}{path}").as_bytes())
+    }
+}
+
+impl HashFs<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, HashFsError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> HashFs<R> {
+    pub fn new(mut reader: R) -> Result<Self, HashFsError> {
+        let header = read_at(&mut reader, 0, HEADER_SIZE)?;
+        let (_, (version, salt, hash_method)) =
+            common_header(&header).map_err(|_| HashFsError::InvalidHeader)?;
+        if hash_method != HASH_METHOD {
+            return Err(HashFsError::UnsupportedHashMethod);
+        }
+        let entries = match version {
+            1 => read_v1_entries(&mut reader)?,
+            2 => read_v2_entries(&mut reader)?,
+            version => return Err(HashFsError::UnsupportedVersion(version)),
+        };
+        Ok(Self {
+            reader,
+            version,
+            salt,
+            entries: entries
+                .into_iter()
+                .map(|entry| (entry.hash, entry))
+                .collect(),
+        })
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    pub fn salt(&self) -> u16 {
+        self.salt
+    }
+
+    /// All entries, in no particular order. Paths are only known for entries
+    /// reachable through directory listings.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.values()
+    }
+
+    pub fn entry(&self, path: &str) -> Option<&Entry> {
+        self.entries.get(&hash_path(path, self.salt))
+    }
+
+    /// Read and decompress the content of a file.
+    pub fn read(&mut self, path: &str) -> Result<Vec<u8>, HashFsError> {
+        let entry = *self
+            .entry(path)
+            .ok_or_else(|| HashFsError::NotFound(path.to_string()))?;
+        if !entry.is_supported {
+            return Err(HashFsError::UnsupportedEntry(path.to_string()));
+        }
+        let content = read_at(
+            &mut self.reader,
+            entry.offset,
+            entry.compressed_size as usize,
+        )?;
+        match entry.compression {
+            Compression::None => Ok(content),
+            Compression::Zlib => {
+                let mut decompressed = Vec::new();
+                ZlibDecoder::new(content.as_slice())
+                    .take(u64::from(entry.size))
+                    .read_to_end(&mut decompressed)?;
+                Ok(decompressed)
+            }
+        }
+    }
+
+    /// List a directory. The root directory is the empty path.
+    pub fn list_dir(&mut self, path: &str) -> Result<DirListing, HashFsError> {
+        let entry = self
+            .entry(path)
+            .ok_or_else(|| HashFsError::NotFound(path.to_string()))?;
+        if !entry.is_directory {
+            return Err(HashFsError::NotADirectory(path.to_string()));
+        }
+        let content = self.read(path)?;
+        let listing = match self.version {
+            1 => parse_v1_directory(&content),
+            _ => parse_v2_directory(&content),
+        };
+        listing.ok_or_else(|| HashFsError::InvalidDirectory(path.to_string()))
+    }
+
+    /// Extract a file, or a directory with all its contents, into
+    /// `output_dir`, keeping the path inside the archive. Returns the number
+    /// of extracted files.
+    ///
+    /// Directories listing names that would escape `output_dir`, or listing
+    /// themselves, are reported as [`HashFsError::InvalidDirectory`].
+    pub fn extract(&mut self, path: &str, output_dir: &Path) -> Result<usize, HashFsError> {
+        let path = normalize(path);
+        if !path.is_empty() && !path.split('/').all(is_valid_name) {
+            return Err(HashFsError::InvalidPath(path.to_string()));
+        }
+        self.extract_entry(path, output_dir, &mut HashSet::new())
+    }
+
+    fn extract_entry(
+        &mut self,
+        path: &str,
+        output_dir: &Path,
+        visited: &mut HashSet<u64>,
+    ) -> Result<usize, HashFsError> {
+        let entry = *self
+            .entry(path)
+            .ok_or_else(|| HashFsError::NotFound(path.to_string()))?;
+        if !entry.is_directory {
+            let output = output_path(output_dir, path)?;
+            if let Some(parent) = output.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(output, self.read(path)?)?;
+            return Ok(1);
+        }
+        if !visited.insert(entry.hash) {
+            return Err(HashFsError::InvalidDirectory(path.to_string()));
+        }
+        let listing = self.list_dir(path)?;
+        let mut count = 0;
+        for name in listing.directories.iter().chain(&listing.files) {
+            if !is_valid_name(name) {
+                return Err(HashFsError::InvalidDirectory(path.to_string()));
+            }
+            count += self.extract_entry(&join(path, name), output_dir, visited)?;
+        }
+        Ok(count)
+    }
+}
+
+/// Whether a name of a directory listing is a single path component.
+fn is_valid_name(name: &str) -> bool {
+    !matches!(name, "" | "." | "..") && !name.contains(['/', '\\'])
+}
+
+/// The output path of an entry, which must stay inside `output_dir`.
+fn output_path(output_dir: &Path, path: &str) -> Result<PathBuf, HashFsError> {
+    let relative = Path::new(path);
+    let output = output_dir.join(relative);
+    let is_inside = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        && output.starts_with(output_dir);
+    if !is_inside {
+        return Err(HashFsError::InvalidPath(path.to_string()));
+    }
+    Ok(output)
+}
+
+fn normalize(path: &str) -> &str {
+    path.trim_matches('/')
+}
+
+fn join(directory: &str, name: &str) -> String {
+    if directory.is_empty() {
+        name.to_string()
+    } else {
+        format!("{directory}/{name}")
+    }
+}
+
+/// Read `len` bytes at `offset`, checking that they are inside the stream
+/// before allocating the buffer.
+fn read_at(reader: &mut (impl Read + Seek), offset: u64, len: usize) -> io::Result<Vec<u8>> {
+    let stream_len = reader.seek(SeekFrom::End(0))?;
+    let end = u64::try_from(len)
+        .ok()
+        .and_then(|len| offset.checked_add(len));
+    if end.is_none_or(|end| end > stream_len) {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "entry past the end of the archive",
+        ));
+    }
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![0; len];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+fn common_header(input: &[u8]) -> IResult<&[u8], (u16, u16, &[u8])> {
+    let (input, _) = tag(MAGIC)(input)?;
+    tuple((le_u16, le_u16, take(4usize)))(input)
+}
+
+fn read_v1_entries(reader: &mut (impl Read + Seek)) -> Result<Vec<Entry>, HashFsError> {
+    let header = read_at(reader, 0, V1_HEADER_SIZE)?;
+    let (_, (entry_count, entries_offset)) =
+        v1_header(&header[HEADER_SIZE..]).map_err(|_| HashFsError::InvalidHeader)?;
+    let table_size = (entry_count as usize)
+        .checked_mul(V1_ENTRY_SIZE)
+        .ok_or(HashFsError::InvalidEntryTable)?;
+    let table = read_at(reader, u64::from(entries_offset), table_size)?;
+    table
+        .chunks_exact(V1_ENTRY_SIZE)
+        .map(|entry| {
+            v1_entry(entry)
+                .map(|(_, entry)| entry)
+                .map_err(|_| HashFsError::InvalidEntryTable)
+        })
+        .collect()
+}
+
+fn v1_header(input: &[u8]) -> IResult<&[u8], (u32, u32)> {
+    tuple((le_u32, le_u32))(input)
+}
+
+fn v1_entry(input: &[u8]) -> IResult<&[u8], Entry> {
+    let (input, (hash, offset, flags, _crc, size, compressed_size)) =
+        tuple((le_u64, le_u64, le_u32, le_u32, le_u32, le_u32))(input)?;
+    let is_compressed = flags & V1_FLAG_COMPRESSED != 0;
+    Ok((
+        input,
+        Entry {
+            hash,
+            offset,
+            size,
+            compressed_size: if is_compressed { compressed_size } else { size },
+            compression: if is_compressed {
+                Compression::Zlib
+            } else {
+                Compression::None
+            },
+            is_directory: flags & V1_FLAG_DIRECTORY != 0,
+            is_supported: true,
+        },
+    ))
+}
+
+fn read_v2_entries(reader: &mut (impl Read + Seek)) -> Result<Vec<Entry>, HashFsError> {
+    let header = read_at(reader, 0, V2_HEADER_SIZE)?;
+    let (_, header) = v2_header(&header[HEADER_SIZE..]).map_err(|_| HashFsError::InvalidHeader)?;
+    let (entry_count, entries_size, metadata_count, metadata_size, entries_offset, metadata_offset) =
+        header;
+    // The counts bound the inflated tables, so that a small table cannot
+    // inflate into an arbitrary amount of memory
+    let entries_len = u64::from(entry_count) * V2_ENTRY_SIZE as u64;
+    let metadata_len = u64::from(metadata_count) * 4;
+    let entries = read_table(reader, entries_offset, entries_size, entries_len)?;
+    let metadata = read_table(reader, metadata_offset, metadata_size, metadata_len)?;
+    if entries.len() as u64 != entries_len || metadata.len() as u64 != metadata_len {
+        return Err(HashFsError::InvalidEntryTable);
+    }
+    let metadata = metadata
+        .chunks_exact(4)
+        .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    entries
+        .chunks_exact(V2_ENTRY_SIZE)
+        .map(|entry| v2_entry(entry, &metadata))
+        .collect()
+}
+
+/// The header fields after the common header: the entry count and the
+/// compressed size of the entry table, the number of metadata words and the
+/// compressed size of the metadata table, and the offsets of both tables.
+type V2Header = (u32, u32, u32, u32, u64, u64);
+
+fn v2_header(input: &[u8]) -> IResult<&[u8], V2Header> {
+    tuple((le_u32, le_u32, le_u32, le_u32, le_u64, le_u64))(input)
+}
+
+/// Read a zlib compressed table of `size` bytes, inflating at most `len`
+/// bytes.
+fn read_table(
+    reader: &mut (impl Read + Seek),
+    offset: u64,
+    size: u32,
+    len: u64,
+) -> Result<Vec<u8>, HashFsError> {
+    let compressed = read_at(reader, offset, size as usize)?;
+    let mut table = Vec::new();
+    ZlibDecoder::new(compressed.as_slice())
+        .take(len)
+        .read_to_end(&mut table)?;
+    Ok(table)
+}
+
+fn v2_entry(input: &[u8], metadata: &[u32]) -> Result<Entry, HashFsError> {
+    let (_, (hash, metadata_index, metadata_count, flags)) =
+        v2_entry_fields(input).map_err(|_| HashFsError::InvalidEntryTable)?;
+    let headers = metadata
+        .get(metadata_index as usize..metadata_index as usize + metadata_count as usize)
+        .ok_or(HashFsError::InvalidEntryTable)?;
+    let plain = headers.iter().find_map(|header| {
+        let kind = (header >> 24) as u8;
+        let index = (header & 0xff_ffff) as usize;
+        matches!(kind, V2_METADATA_PLAIN | V2_METADATA_DIRECTORY).then_some(index)
+    });
+    let Some(index) = plain else {
+        return Ok(Entry {
+            hash,
+            offset: 0,
+            size: 0,
+            compressed_size: 0,
+            compression: Compression::None,
+            is_directory: false,
+            is_supported: false,
+        });
+    };
+    let Some(&[packed_size, packed_flags, _, offset_block]) = metadata.get(index..index + 4) else {
+        return Err(HashFsError::InvalidEntryTable);
+    };
+    let compression = match packed_size >> 28 {
+        0 => Compression::None,
+        1 => Compression::Zlib,
+        compression => return Err(HashFsError::UnsupportedCompression(compression)),
+    };
+    Ok(Entry {
+        hash,
+        offset: u64::from(offset_block) * V2_OFFSET_BLOCK_SIZE,
+        size: packed_flags & 0x0fff_ffff,
+        compressed_size: packed_size & 0x0fff_ffff,
+        compression,
+        is_directory: flags & V2_FLAG_DIRECTORY != 0,
+        is_supported: true,
+    })
+}
+
+fn v2_entry_fields(input: &[u8]) -> IResult<&[u8], (u64, u32, u16, u16)> {
+    tuple((le_u64, le_u32, le_u16, le_u16))(input)
+}
+
+fn parse_v1_directory(content: &[u8]) -> Option<DirListing> {
+    let content = std::str::from_utf8(content).ok()?;
+    let mut listing = DirListing::default();
+    for name in content.lines().filter(|name| !name.is_empty()) {
+        match name.strip_prefix('*') {
+            Some(directory) => listing.directories.push(directory.to_string()),
+            None => listing.files.push(name.to_string()),
+        }
+    }
+    Some(listing)
+}
+
+fn parse_v2_directory(content: &[u8]) -> Option<DirListing> {
+    let count = u32::from_le_bytes(content.get(..4)?.try_into().ok()?) as usize;
+    let lengths = content.get(4..4 + count)?;
+    let mut names = &content[4 + count..];
+    let mut listing = DirListing::default();
+    for &len in lengths {
+        let name = std::str::from_utf8(names.get(..len as usize)?).ok()?;
+        names = &names[len as usize..];
+        match name.strip_prefix('/') {
+            Some(directory) => listing.directories.push(directory.to_string()),
+            None => listing.files.push(name.to_string()),
+        }
+    }
+    Some(listing)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::write::ZlibEncoder;
+
+    use super::*;
+
+    const CITY: &[u8] = b"[cargo_data]\nname: apples\n";
+    const README: &[u8] = b"readme";
+
+    fn zlib(content: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(content).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Build a version 1 archive with a compressed file in a subdirectory and
+    /// an uncompressed file in the root.
+    fn v1_archive() -> Vec<u8> {
+        v1_archive_of(&[
+            ("", b"*def\nreadme.txt\n".to_vec(), V1_FLAG_DIRECTORY),
+            ("def", b"city.sii\n".to_vec(), V1_FLAG_DIRECTORY),
+            ("def/city.sii", CITY.to_vec(), V1_FLAG_COMPRESSED),
+            ("readme.txt", README.to_vec(), 0),
+        ])
+    }
+
+    /// Build a version 1 archive of paths with their content and flags.
+    fn v1_archive_of(files: &[(&str, Vec<u8>, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut table = Vec::new();
+        let data_offset = (V1_HEADER_SIZE + files.len() * V1_ENTRY_SIZE) as u64;
+        for (path, content, flags) in files {
+            let stored = if flags & V1_FLAG_COMPRESSED != 0 {
+                zlib(content)
+            } else {
+                content.clone()
+            };
+            table.extend(hash_path(path, 0).to_le_bytes());
+            table.extend((data_offset + data.len() as u64).to_le_bytes());
+            table.extend(flags.to_le_bytes());
+            table.extend(0u32.to_le_bytes());
+            table.extend((content.len() as u32).to_le_bytes());
+            table.extend((stored.len() as u32).to_le_bytes());
+            data.extend(stored);
+        }
+        let mut archive = b"SCS#\x01\x00\x00\x00CITY".to_vec();
+        archive.extend((files.len() as u32).to_le_bytes());
+        archive.extend((V1_HEADER_SIZE as u32).to_le_bytes());
+        archive.extend(table);
+        archive.extend(data);
+        archive
+    }
+
+    fn v2_directory(names: &[&str]) -> Vec<u8> {
+        let mut content = (names.len() as u32).to_le_bytes().to_vec();
+        content.extend(names.iter().map(|name| name.len() as u8));
+        content.extend(names.iter().flat_map(|name| name.bytes()));
+        content
+    }
+
+    /// Build a version 2 archive with the same layout as [`v1_archive`], plus
+    /// a texture entry without plain metadata.
+    fn v2_archive() -> Vec<u8> {
+        let files = [
+            ("", v2_directory(&["/def", "readme.txt"]), true, false),
+            ("def", v2_directory(&["city.sii"]), true, false),
+            ("def/city.sii", CITY.to_vec(), false, true),
+            ("readme.txt", README.to_vec(), false, false),
+        ];
+        let mut data = Vec::new();
+        let mut entries = Vec::new();
+        let mut metadata = Vec::<u32>::new();
+        for (path, content, is_directory, is_compressed) in &files {
+            let stored = if *is_compressed {
+                zlib(content)
+            } else {
+                content.clone()
+            };
+            let kind = if *is_directory {
+                V2_METADATA_DIRECTORY
+            } else {
+                V2_METADATA_PLAIN
+            };
+            entries.extend(hash_path(path, 0).to_le_bytes());
+            entries.extend((metadata.len() as u32).to_le_bytes());
+            entries.extend(1u16.to_le_bytes());
+            entries.extend(u16::from(*is_directory).to_le_bytes());
+            let index = metadata.len() as u32 + 1;
+            metadata.push(u32::from(kind) << 24 | index);
+            metadata.push(stored.len() as u32 | u32::from(*is_compressed) << 28);
+            metadata.push(content.len() as u32);
+            metadata.push(0);
+            metadata.push((V2_HEADER_SIZE as u32).div_ceil(16) + data.len() as u32 / 16);
+            data.extend(&stored);
+            data.resize(data.len().next_multiple_of(16), 0);
+        }
+        entries.extend(hash_path("def/texture.tobj", 0).to_le_bytes());
+        entries.extend((metadata.len() as u32).to_le_bytes());
+        entries.extend(1u16.to_le_bytes());
+        entries.extend(0u16.to_le_bytes());
+        metadata.push(1 << 24 | (metadata.len() as u32 + 1));
+
+        let entries_table = zlib(&entries);
+        let metadata_table = zlib(
+            &metadata
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect::<Vec<_>>(),
+        );
+        let data_offset = (V2_HEADER_SIZE as u64).next_multiple_of(16);
+        let entries_offset = data_offset + data.len() as u64;
+        let metadata_offset = entries_offset + entries_table.len() as u64;
+
+        let mut archive = b"SCS#\x02\x00\x00\x00CITY".to_vec();
+        archive.extend((files.len() as u32 + 1).to_le_bytes());
+        archive.extend((entries_table.len() as u32).to_le_bytes());
+        archive.extend((metadata.len() as u32).to_le_bytes());
+        archive.extend((metadata_table.len() as u32).to_le_bytes());
+        archive.extend(entries_offset.to_le_bytes());
+        archive.extend(metadata_offset.to_le_bytes());
+        archive.resize(data_offset as usize, 0);
+        archive.extend(data);
+        archive.extend(entries_table);
+        archive.extend(metadata_table);
+        archive
+    }
+
+    fn assert_reads_archive(archive: Vec<u8>, version: u16) {
+        let mut hashfs = HashFs::new(Cursor::new(archive)).unwrap();
+
+        assert_eq!(hashfs.version(), version);
+        assert_eq!(
+            hashfs.list_dir("").unwrap(),
+            DirListing {
+                directories: vec!["def".to_string()],
+                files: vec!["readme.txt".to_string()],
+            }
+        );
+        assert_eq!(hashfs.list_dir("/def/").unwrap().files, ["city.sii"]);
+        assert_eq!(hashfs.read("def/city.sii").unwrap(), CITY);
+        assert_eq!(hashfs.read("/readme.txt").unwrap(), README);
+        assert!(matches!(
+            hashfs.read("def/missing.sii"),
+            Err(HashFsError::NotFound(_))
+        ));
+        assert!(matches!(
+            hashfs.list_dir("readme.txt"),
+            Err(HashFsError::NotADirectory(_))
+        ));
+
+        let output_dir = std::env::temp_dir().join(format!(
+            "sii-decode-hashfs-v{version}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&output_dir);
+        assert_eq!(hashfs.extract("", &output_dir).unwrap(), 2);
+        assert_eq!(fs::read(output_dir.join("def/city.sii")).unwrap(), CITY);
+        assert_eq!(fs::read(output_dir.join("readme.txt")).unwrap(), README);
+        fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    fn temp_output_dir(name: &str) -> PathBuf {
+        let output_dir =
+            std::env::temp_dir().join(format!("sii-decode-hashfs-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_dir);
+        output_dir
+    }
+
+    #[test]
+    fn hashes_paths() {
+        assert_eq!(hash_path("", 0), 0x9ae16a3b2f90404f);
+        assert_eq!(hash_path("/def/city.sii", 0), hash_path("def/city.sii", 0));
+        assert_eq!(hash_path("def", 7), city_hash_64(b"7def"));
+    }
+
+    #[test]
+    fn reads_v1_archive() {
+        assert_reads_archive(v1_archive(), 1);
+    }
+
+    #[test]
+    fn reads_v2_archive() {
+        assert_reads_archive(v2_archive(), 2);
+        let mut hashfs = HashFs::new(Cursor::new(v2_archive())).unwrap();
+        assert!(matches!(
+            hashfs.read("def/texture.tobj"),
+            Err(HashFsError::UnsupportedEntry(_))
+        ));
+    }
+
+    #[test]
+    fn bounds_v2_tables_by_their_counts() {
+        let mut archive = v2_archive();
+        archive[HEADER_SIZE..HEADER_SIZE + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            HashFs::new(Cursor::new(archive)),
+            Err(HashFsError::InvalidEntryTable)
+        ));
+
+        let mut archive = v2_archive();
+        archive[HEADER_SIZE..HEADER_SIZE + 4].copy_from_slice(&1u32.to_le_bytes());
+        let hashfs = HashFs::new(Cursor::new(archive)).unwrap();
+        assert_eq!(hashfs.entries.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_headers() {
+        assert!(matches!(
+            HashFs::new(Cursor::new(b"ScsC\x01\x00\x00\x00CITY".to_vec())),
+            Err(HashFsError::InvalidHeader)
+        ));
+        assert!(matches!(
+            HashFs::new(Cursor::new(b"SCS#\x03\x00\x00\x00CITY".to_vec())),
+            Err(HashFsError::UnsupportedVersion(3))
+        ));
+    }
+
+    #[test]
+    fn rejects_escaping_names_on_extract() {
+        let parent = temp_output_dir("escape");
+        let output_dir = parent.join("output");
+        for name in ["../evil.txt", "..", ".", "def\\..\\evil.txt"] {
+            let archive = v1_archive_of(&[
+                ("", format!("{name}\n").into_bytes(), V1_FLAG_DIRECTORY),
+                (&format!("/{name}"), README.to_vec(), 0),
+            ]);
+            let mut hashfs = HashFs::new(Cursor::new(archive)).unwrap();
+
+            assert!(
+                matches!(
+                    hashfs.extract("", &output_dir),
+                    Err(HashFsError::InvalidDirectory(path)) if path.is_empty()
+                ),
+                "{name}"
+            );
+        }
+        assert!(!parent.join("evil.txt").exists());
+
+        let mut hashfs = HashFs::new(Cursor::new(v1_archive())).unwrap();
+        assert!(matches!(
+            hashfs.extract("def/../readme.txt", &output_dir),
+            Err(HashFsError::InvalidPath(_))
+        ));
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn rejects_directories_listing_themselves() {
+        let archive = v1_archive_of(&[
+            ("", b"*def\n".to_vec(), V1_FLAG_DIRECTORY),
+            ("def", b"*\n".to_vec(), V1_FLAG_DIRECTORY),
+        ]);
+        let mut hashfs = HashFs::new(Cursor::new(archive)).unwrap();
+        let output_dir = temp_output_dir("cycle");
+
+        assert!(matches!(
+            hashfs.extract("", &output_dir),
+            Err(HashFsError::InvalidDirectory(path)) if path == "def"
+        ));
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn rejects_sizes_past_the_end() {
+        let mut archive = v1_archive();
+        archive[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            HashFs::new(Cursor::new(archive)),
+            Err(HashFsError::Io(err)) if err.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let archive = v1_archive_of(&[("readme.txt", README.to_vec(), 0)]);
+        let mut hashfs = HashFs::new(Cursor::new(archive)).unwrap();
+        hashfs
+            .entries
+            .get_mut(&hash_path("readme.txt", 0))
+            .unwrap()
+            .compressed_size = u32::MAX;
+        assert!(matches!(
+            hashfs.read("readme.txt"),
+            Err(HashFsError::Io(err)) if err.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+//! Small helpers for the hand-written JSON renderers in this crate.
+
+/// Escape a string so that it can be embedded in a JSON string literal.
+pub(crate) fn json_escape(value: &str) -> String {
+    let mut escaped = String::new();
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Render a list of strings as a JSON array on a single line.
+pub(crate) fn render_string_iter<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let values = values
+        .map(|value| format!("\"{}\"", json_escape(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("[{values}]")
+}
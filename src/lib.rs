@@ -1,9 +1,23 @@
 pub mod bsii_file;
 mod bsii_output;
 mod bsii_parse;
+mod bsii_write;
+mod cityhash;
+pub mod config;
+pub mod csv;
+pub mod edit;
 pub mod ets2;
 pub mod file_type;
+pub mod hashfs;
+mod json;
+pub mod schema;
 pub mod scsc_file;
 mod scsc_parse;
+pub mod search;
+mod siin_parse;
+mod siin_value;
+#[cfg(all(feature = "sqlite", not(target_arch = "wasm32")))]
+pub mod sqlite;
+pub mod three_nk;
 #[cfg(feature = "wasm")]
 pub mod wasm;
@@ -1,8 +1,79 @@
 use std::env;
 use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
 
 use simple_logger::SimpleLogger;
 
+use sii_decode::bsii_file::BsiiFile;
+use sii_decode::config::Config;
+use sii_decode::csv::{prototype_to_csv, ArrayLayout};
+use sii_decode::edit::set_field_in_file;
+use sii_decode::ets2::{
+    evaluate_achievements, list_profiles, load_profile, load_save_game, render_analysis_json,
+    save_dependencies, AchievementRegistry, AchievementStatus, ModReport, SaveWatcher,
+};
+use sii_decode::file_type::{convert, decode_until_bsii, FileType};
+use sii_decode::hashfs::HashFs;
+use sii_decode::schema::Schema;
+use sii_decode::search::{search, Pattern};
+
+const USAGE: &str = "usage:
+  sii-decode [--ets2-achievements] path/to/file.sii
+  sii-decode profile path/to/profile.sii
+  sii-decode profiles path/to/profiles
+  sii-decode mods path/to/profile.sii path/to/info.sii
+  sii-decode schema [--markdown] path/to/file.sii
+  sii-decode schema [--markdown] --compare path/to/old.sii path/to/new.sii
+  sii-decode convert --to <siin|bsii|scsc|3nk> path/to/input.sii [path/to/output.sii]
+  sii-decode csv [--flatten] [--output path/to/output.csv] <prototype> path/to/file.sii
+  sii-decode csv --ets2-deliveries [--output path/to/output.csv] path/to/game.sii
+  sii-decode grep [--regex | --number] <pattern> path/to/file.sii
+  sii-decode sqlite [--append] path/to/output.db path/to/file.sii...
+  sii-decode config [--output path/to/output.cfg] path/to/config.cfg [<name> [<value>]]
+  sii-decode scs [--extract path/to/output] path/to/archive.scs [<path>]
+  sii-decode set [--siin] [--output path/to/output.sii] path/to/file.sii <unit-id>.<field> <value>
+  sii-decode watch [--interval seconds] [--output path/to/analysis.json] path/to/profile
+
+The type of input files is detected from their content. Use `-` as a path to
+read from stdin or write to stdout.";
+
+#[derive(Debug)]
+enum Error {
+    Usage,
+    Io { path: PathBuf, source: io::Error },
+    Decode { path: PathBuf, message: String },
+}
+
+impl Error {
+    fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    fn decode(path: impl Into<PathBuf>, message: impl ToString) -> Self {
+        Self::Decode {
+            path: path.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Usage => write!(f, "{USAGE}"),
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Decode { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
 fn main() {
     SimpleLogger::new()
         .with_level(log::LevelFilter::Info)
@@ -10,20 +81,495 @@ fn main() {
         .unwrap();
 
     let args = env::args().skip(1).collect::<Vec<_>>();
-    let (analyze_ets2, path) = match args.as_slice() {
-        [flag, path] if flag == "--ets2-achievements" => (true, path),
-        [path] => (false, path),
-        _ => panic!("usage: sii-decode [--ets2-achievements] path/to/file.sii"),
+    if let Err(err) = run(&args) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Error> {
+    match args {
+        [command, path] if command == "profile" => {
+            let profile = load_profile(&read(path)?).map_err(|err| Error::decode(path, err))?;
+            print!("{}", profile.to_json());
+            Ok(())
+        }
+        [command, path] if command == "profiles" => profiles(path),
+        [command, profile_path, save_path] if command == "mods" => mods(profile_path, save_path),
+        [command, rest @ ..] if command == "schema" => schema(rest),
+        [command, rest @ ..] if command == "convert" => convert_file(rest),
+        [command, rest @ ..] if command == "csv" => csv(rest),
+        [command, rest @ ..] if command == "grep" => grep(rest),
+        [command, rest @ ..] if command == "config" => config(rest),
+        [command, rest @ ..] if command == "scs" => scs(rest),
+        [command, rest @ ..] if command == "set" => set(rest),
+        [command, rest @ ..] if command == "sqlite" => sqlite(rest),
+        [command, rest @ ..] if command == "watch" => watch(rest),
+        [flag, path] if flag == "--ets2-achievements" => {
+            let content = read(path)?;
+            let analysis = sii_decode::ets2::analyze_save_to_json(&content)
+                .map_err(|err| Error::decode(path, err))?;
+            println!("{analysis}");
+            Ok(())
+        }
+        [path] => {
+            let content = read(path)?;
+            let decoded = sii_decode::file_type::decode_until_siin(&content)
+                .map_err(|err| Error::decode(path, err))?;
+            write("-", &decoded)
+        }
+        _ => Err(Error::Usage),
+    }
+}
+
+/// Compare the mods required by a save with the mods active in a profile.
+fn mods(profile_path: &str, save_path: &str) -> Result<(), Error> {
+    let profile =
+        load_profile(&read(profile_path)?).map_err(|err| Error::decode(profile_path, err))?;
+    let content = read(save_path)?;
+    let bsii_content = decode_until_bsii(&content).map_err(|err| Error::decode(save_path, err))?;
+    let bsii = BsiiFile::parse(&bsii_content).map_err(|err| Error::decode(save_path, err))?;
+    let report = ModReport::new(&save_dependencies(&bsii), &profile.active_mods);
+
+    println!("required by the save:");
+    for dependency in &report.required {
+        println!("  {} ({})", dependency.id, dependency.name);
+    }
+    println!("active in the profile:");
+    for active_mod in &report.active {
+        println!("  {} ({})", active_mod.id, active_mod.name);
+    }
+    println!("missing:");
+    for dependency in &report.missing {
+        println!("  {} ({})", dependency.id, dependency.name);
+    }
+    println!("not required:");
+    for active_mod in &report.unused {
+        println!("  {} ({})", active_mod.id, active_mod.name);
+    }
+    println!("in a different order:");
+    for difference in &report.order_differences {
+        println!(
+            "  {} (position {} in the save, {} in the profile)",
+            difference.id,
+            difference.save_position + 1,
+            difference.profile_position + 1
+        );
+    }
+    Ok(())
+}
+
+/// List the profiles in a directory with their save slots and save metadata.
+fn profiles(path: &str) -> Result<(), Error> {
+    let profiles = list_profiles(path).map_err(|source| Error::io(path, source))?;
+    for profile in profiles {
+        println!(
+            "{} ({})",
+            profile.name.as_deref().unwrap_or("?"),
+            profile.dir_name
+        );
+        for save in &profile.saves {
+            let info = match save.read_save_info() {
+                Ok(info) if info.name.is_empty() => format!(" (saved at {})", info.file_time),
+                Ok(info) => format!(" \"{}\" (saved at {})", info.name, info.file_time),
+                Err(err) => format!(" ({err})"),
+            };
+            let missing = if save.game_path.is_none() {
+                " (missing game.sii)"
+            } else {
+                ""
+            };
+            println!("  {}{}{}", save.dir_name, info, missing);
+        }
+    }
+    Ok(())
+}
+
+fn schema(args: &[String]) -> Result<(), Error> {
+    let (markdown, args) = take_flag(args, "--markdown");
+    let (compare, args) = take_flag(&args, "--compare");
+    let output = match (compare, args.as_slice()) {
+        (false, [path]) => {
+            let schema = read_schema(path)?;
+            if markdown {
+                schema.to_markdown()
+            } else {
+                schema.to_json()
+            }
+        }
+        (true, [old_path, new_path]) => {
+            let diff = read_schema(old_path)?.compare(&read_schema(new_path)?);
+            if markdown {
+                diff.to_markdown()
+            } else {
+                diff.to_json()
+            }
+        }
+        _ => return Err(Error::Usage),
     };
+    print!("{output}");
+    Ok(())
+}
 
-    let content = fs::read(path).unwrap();
-    if analyze_ets2 {
+fn convert_file(args: &[String]) -> Result<(), Error> {
+    let (target, args) = take_option(args, "--to")?;
+    let target = match target.as_deref() {
+        Some("siin") => FileType::Siin,
+        Some("bsii") => FileType::Bsii,
+        Some("scsc") => FileType::Scsc,
+        Some("3nk") => FileType::ThreeNK,
+        _ => return Err(Error::Usage),
+    };
+    let (path, output) = match args.as_slice() {
+        [path] => (path, "-"),
+        [path, output] => (path, output.as_str()),
+        _ => return Err(Error::Usage),
+    };
+    let content = read(path)?;
+    let converted = convert(&content, target).map_err(|err| Error::decode(path, err))?;
+    write(output, &converted)
+}
+
+fn csv(args: &[String]) -> Result<(), Error> {
+    let (flatten, args) = take_flag(args, "--flatten");
+    let (deliveries, args) = take_flag(&args, "--ets2-deliveries");
+    let (output, args) = take_option(&args, "--output")?;
+    let output = output.as_deref().unwrap_or("-");
+    let csv = match (deliveries, args.as_slice()) {
+        (true, [path]) => {
+            let save = load_save_game(&read(path)?).map_err(|err| Error::decode(path, err))?;
+            save.delivery_log.to_csv()
+        }
+        (false, [prototype, path]) => {
+            let layout = if flatten {
+                ArrayLayout::Flattened
+            } else {
+                ArrayLayout::Joined
+            };
+            let content = read(path)?;
+            let bsii_content =
+                decode_until_bsii(&content).map_err(|err| Error::decode(path, err))?;
+            let bsii = BsiiFile::parse(&bsii_content).map_err(|err| Error::decode(path, err))?;
+            prototype_to_csv(&bsii, prototype, layout)
+                .ok_or_else(|| Error::decode(path, format!("no prototype named {prototype}")))?
+        }
+        _ => return Err(Error::Usage),
+    };
+    write(output, csv.as_bytes())
+}
+
+fn grep(args: &[String]) -> Result<(), Error> {
+    let (regex, args) = take_flag(args, "--regex");
+    let (number, args) = take_flag(&args, "--number");
+    let [pattern, path] = args.as_slice() else {
+        return Err(Error::Usage);
+    };
+    let pattern = match (regex, number) {
+        (false, false) => Pattern::Text(pattern.clone()),
+        (true, false) => Pattern::regex(pattern).map_err(|err| Error::decode(pattern, err))?,
+        (false, true) => Pattern::Number(pattern.parse().map_err(|_| Error::Usage)?),
+        (true, true) => return Err(Error::Usage),
+    };
+    let content = read(path)?;
+    let bsii_content = decode_until_bsii(&content).map_err(|err| Error::decode(path, err))?;
+    let bsii = BsiiFile::parse(&bsii_content).map_err(|err| Error::decode(path, err))?;
+    for hit in search(&bsii, &pattern) {
+        let index = hit
+            .index
+            .map(|index| format!("[{index}]"))
+            .unwrap_or_default();
         println!(
-            "{}",
-            sii_decode::ets2::analyze_save_to_json(&content).unwrap()
+            "{} ({}) {}{}: {}",
+            hit.unit, hit.prototype, hit.field, index, hit.value
         );
-    } else {
-        let decoded = sii_decode::file_type::decode_until_siin(&content).unwrap();
-        println!("{}", String::from_utf8(decoded).unwrap());
     }
+    Ok(())
+}
+
+/// List a directory of a HashFS archive, or extract a file or directory.
+fn scs(args: &[String]) -> Result<(), Error> {
+    let (output, args) = take_option(args, "--extract")?;
+    let (archive, path) = match args.as_slice() {
+        [archive] => (archive, ""),
+        [archive, path] => (archive, path.as_str()),
+        _ => return Err(Error::Usage),
+    };
+    let mut hashfs = HashFs::open(archive).map_err(|err| Error::decode(archive, err))?;
+    if let Some(output) = output {
+        let count = hashfs
+            .extract(path, Path::new(&output))
+            .map_err(|err| Error::decode(archive, err))?;
+        log::info!("extracted {count} files to {output}");
+        return Ok(());
+    }
+    let listing = hashfs
+        .list_dir(path)
+        .map_err(|err| Error::decode(archive, err))?;
+    for directory in &listing.directories {
+        println!("{directory}/");
+    }
+    for file in &listing.files {
+        println!("{file}");
+    }
+    Ok(())
+}
+
+/// Set a field of a save, in place unless `--output` is given. Writing SiiN
+/// requires `--output`, so that a binary save is never replaced by text.
+fn set(args: &[String]) -> Result<(), Error> {
+    let (siin, args) = take_flag(args, "--siin");
+    let (output, args) = take_option(&args, "--output")?;
+    let [path, field, value] = args.as_slice() else {
+        return Err(Error::Usage);
+    };
+    if siin && output.is_none() {
+        return Err(Error::decode(
+            path,
+            "--siin changes the file type, use --output to write a new file",
+        ));
+    }
+    let content = read(path)?;
+    let output_type = siin.then_some(FileType::Siin);
+    let edited = set_field_in_file(&content, field, value, output_type)
+        .map_err(|err| Error::decode(path, err))?;
+    let output = output.as_deref().unwrap_or(path);
+    write(output, &edited)
+}
+
+/// Print the settings of a config file, print a single setting, or change it.
+fn config(args: &[String]) -> Result<(), Error> {
+    let (output, args) = take_option(args, "--output")?;
+    let [path, rest @ ..] = args.as_slice() else {
+        return Err(Error::Usage);
+    };
+    let content = read(path)?;
+    let mut config = Config::parse(&String::from_utf8_lossy(&content))
+        .map_err(|err| Error::decode(path, err))?;
+    match rest {
+        [] => {
+            for (name, value) in config.settings() {
+                println!("{name} = {value}");
+            }
+            Ok(())
+        }
+        [name] => {
+            let value = config
+                .get(name)
+                .ok_or_else(|| Error::decode(path, format!("{name}: no such setting")))?;
+            println!("{value}");
+            Ok(())
+        }
+        [name, value] => {
+            config
+                .set(name, value)
+                .map_err(|err| Error::decode(path, err))?;
+            let output = output.as_deref().unwrap_or(path);
+            write(output, config.to_string().as_bytes())
+        }
+        _ => Err(Error::Usage),
+    }
+}
+
+/// Whether `path` is missing or starts with the SQLite file header, so that
+/// replacing it cannot destroy an unrelated file.
+#[cfg(all(feature = "sqlite", not(target_arch = "wasm32")))]
+fn is_sqlite_or_missing(path: &str) -> Result<bool, Error> {
+    const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+    let mut file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(true),
+        Err(source) => return Err(Error::io(path, source)),
+    };
+    let mut header = [0; SQLITE_HEADER.len()];
+    match file.read_exact(&mut header) {
+        Ok(()) => Ok(&header == SQLITE_HEADER),
+        Err(source) if source.kind() == io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(source) => Err(Error::io(path, source)),
+    }
+}
+
+/// Export saves into a SQLite database, keyed by their path.
+#[cfg(all(feature = "sqlite", not(target_arch = "wasm32")))]
+fn sqlite(args: &[String]) -> Result<(), Error> {
+    let (append, args) = take_flag(args, "--append");
+    let [database, paths @ ..] = args.as_slice() else {
+        return Err(Error::Usage);
+    };
+    if paths.is_empty() {
+        return Err(Error::Usage);
+    }
+    if !append {
+        if !is_sqlite_or_missing(database)? {
+            return Err(Error::decode(
+                database,
+                "not a SQLite database, refusing to replace it",
+            ));
+        }
+        match fs::remove_file(database) {
+            Err(source) if source.kind() != io::ErrorKind::NotFound => {
+                return Err(Error::io(database, source))
+            }
+            _ => {}
+        }
+    }
+    let mut connection =
+        rusqlite::Connection::open(database).map_err(|err| Error::decode(database, err))?;
+    for path in paths {
+        let content = read(path)?;
+        let bsii_content = decode_until_bsii(&content).map_err(|err| Error::decode(path, err))?;
+        let bsii = BsiiFile::parse(&bsii_content).map_err(|err| Error::decode(path, err))?;
+        sii_decode::sqlite::export_to_sqlite(&mut connection, &bsii, path)
+            .map_err(|err| Error::decode(database, err))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(all(feature = "sqlite", not(target_arch = "wasm32"))))]
+fn sqlite(_args: &[String]) -> Result<(), Error> {
+    Err(Error::decode(
+        "sqlite",
+        "this build does not include SQLite support, rebuild with `--features sqlite`",
+    ))
+}
+
+/// Re-analyze the saves of a profile whenever the game writes one, printing
+/// the achievements that changed since the previous save to stderr so that
+/// the analysis JSON written to stdout stays valid.
+fn watch(args: &[String]) -> Result<(), Error> {
+    let (interval, args) = take_option(args, "--interval")?;
+    let (output, args) = take_option(&args, "--output")?;
+    let interval = match interval {
+        Some(interval) => interval.parse::<f64>().map_err(|_| Error::Usage)?,
+        None => 2.0,
+    };
+    // Negative and NaN intervals are rejected by the conversion
+    let interval = Duration::try_from_secs_f64(interval)
+        .ok()
+        .filter(|interval| !interval.is_zero())
+        .ok_or(Error::Usage)?;
+    let output = output.as_deref().unwrap_or("-");
+    let [profile_dir] = args.as_slice() else {
+        return Err(Error::Usage);
+    };
+
+    let mut watcher =
+        SaveWatcher::new(profile_dir).map_err(|source| Error::io(profile_dir, source))?;
+    // Use the latest existing save as the baseline for the first diff
+    let mut previous = watcher
+        .latest()
+        .and_then(|path| load_save_game(&read(path).ok()?).ok())
+        .map(|save| evaluate_achievements(&save));
+    log::info!("Watching {}", watcher.save_dir().display());
+    loop {
+        thread::sleep(interval);
+        let changed = watcher
+            .poll()
+            .map_err(|source| Error::io(watcher.save_dir(), source))?;
+        for path in changed {
+            // A save that fails to decode is reported and skipped, the next
+            // save will be analyzed as usual
+            let save = match read(&path).and_then(|content| {
+                load_save_game(&content).map_err(|err| Error::decode(&path, err))
+            }) {
+                Ok(save) => save,
+                Err(err) => {
+                    log::warn!("{err}");
+                    continue;
+                }
+            };
+            let achievements = evaluate_achievements(&save);
+            eprintln!("{}", path.display());
+            eprint!(
+                "{}",
+                render_achievement_changes(&achievements, previous.as_ref())
+            );
+            write(output, render_analysis_json(&save).as_bytes())?;
+            previous = Some(achievements);
+        }
+    }
+}
+
+fn render_achievement_changes(
+    achievements: &AchievementRegistry,
+    previous: Option<&AchievementRegistry>,
+) -> String {
+    let Some(previous) = previous else {
+        return String::new();
+    };
+    let changes = achievements.changes_since(previous);
+    if changes.is_empty() {
+        return "  no achievement progress changed\n".to_string();
+    }
+    changes
+        .iter()
+        .map(|change| {
+            let completed = if change.previous_status == AchievementStatus::InProgress
+                && change.status == AchievementStatus::Complete
+            {
+                " (completed)"
+            } else {
+                ""
+            };
+            format!(
+                "  {}: {} -> {} / {} {}{}\n",
+                change.display_name,
+                change.previous.current,
+                change.progress.current,
+                change.progress.target,
+                change.progress.unit,
+                completed
+            )
+        })
+        .collect()
+}
+
+/// Remove all occurrences of `flag` from `args`, returning whether it was present.
+fn take_flag(args: &[String], flag: &str) -> (bool, Vec<String>) {
+    let present = args.iter().any(|arg| arg == flag);
+    let rest = args.iter().filter(|arg| *arg != flag).cloned().collect();
+    (present, rest)
+}
+
+/// Remove an option and its value from `args`, returning the value if present.
+fn take_option(args: &[String], option: &str) -> Result<(Option<String>, Vec<String>), Error> {
+    let Some(position) = args.iter().position(|arg| arg == option) else {
+        return Ok((None, args.to_vec()));
+    };
+    let value = args.get(position + 1).ok_or(Error::Usage)?.clone();
+    let mut rest = args.to_vec();
+    rest.drain(position..=position + 1);
+    Ok((Some(value), rest))
+}
+
+/// Read a file, or stdin if the path is `-`.
+fn read(path: impl AsRef<Path>) -> Result<Vec<u8>, Error> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut content = Vec::new();
+        io::stdin()
+            .read_to_end(&mut content)
+            .map_err(|source| Error::io(path, source))?;
+        return Ok(content);
+    }
+    fs::read(path).map_err(|source| Error::io(path, source))
+}
+
+/// Write the content unchanged to a file, or stdout if the path is `-`.
+fn write(path: impl AsRef<Path>, content: &[u8]) -> Result<(), Error> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut stdout = io::stdout().lock();
+        return stdout
+            .write_all(content)
+            .and_then(|()| stdout.flush())
+            .map_err(|source| Error::io(path, source));
+    }
+    fs::write(path, content).map_err(|source| Error::io(path, source))
+}
+
+fn read_schema(path: &str) -> Result<Schema, Error> {
+    let content = read(path)?;
+    let bsii_content = decode_until_bsii(&content).map_err(|err| Error::decode(path, err))?;
+    let bsii = BsiiFile::parse(&bsii_content).map_err(|err| Error::decode(path, err))?;
+    Ok(Schema::from_bsii(&bsii))
 }
@@ -0,0 +1,466 @@
+//! Exports the prototypes of a BSII file as a schema.
+//!
+//! The prototypes in a BSII file describe every unit class and field that the
+//! game serialized, so they effectively form the save schema of the game
+//! version that wrote the file. A [`Schema`] owns a copy of those definitions,
+//! which allows schemas from different files to be compared with
+//! [`Schema::compare`].
+
+use std::collections::BTreeMap;
+
+use crate::bsii_file::{type_name, BsiiFile, Prototype, ValuePrototype};
+use crate::json::{json_escape, render_string_iter};
+
+/// The prototypes of a BSII file, sorted by prototype ID.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Schema {
+    pub prototypes: Vec<PrototypeSchema>,
+}
+
+/// A unit class definition, with the fields in their serialization order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PrototypeSchema {
+    pub name: String,
+    pub fields: Vec<FieldSchema>,
+}
+
+/// A field definition of a unit class.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldSchema {
+    pub name: String,
+    pub type_id: u32,
+    /// Enum value table, only present for enum fields (type ID 0x37).
+    pub enum_values: Option<BTreeMap<u32, String>>,
+}
+
+/// Differences between two schemas, usually from different game versions.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SchemaDiff {
+    pub added_prototypes: Vec<String>,
+    pub removed_prototypes: Vec<String>,
+    pub added_fields: Vec<FieldChange>,
+    pub removed_fields: Vec<FieldChange>,
+    pub retyped_fields: Vec<RetypedField>,
+}
+
+/// A field that only exists in one of the compared schemas.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldChange {
+    pub prototype: String,
+    pub field: String,
+    pub type_id: u32,
+}
+
+/// A field whose type differs between the compared schemas.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RetypedField {
+    pub prototype: String,
+    pub field: String,
+    pub old_type_id: u32,
+    pub new_type_id: u32,
+}
+
+impl Schema {
+    pub fn from_bsii(bsii: &BsiiFile<'_>) -> Self {
+        let mut prototypes = bsii.prototypes().collect::<Vec<_>>();
+        prototypes.sort_by_key(|prototype| prototype.id());
+        Self {
+            prototypes: prototypes.into_iter().map(PrototypeSchema::from).collect(),
+        }
+    }
+
+    /// Look up a prototype by name.
+    pub fn prototype(&self, name: &str) -> Option<&PrototypeSchema> {
+        self.prototypes
+            .iter()
+            .find(|prototype| prototype.name == name)
+    }
+
+    /// Compare this schema against a newer one.
+    ///
+    /// Prototypes and fields are matched by name, since prototype IDs are
+    /// assigned per file and are not stable across saves.
+    pub fn compare(&self, newer: &Schema) -> SchemaDiff {
+        let mut diff = SchemaDiff::default();
+        for prototype in &newer.prototypes {
+            if self.prototype(&prototype.name).is_none() {
+                diff.added_prototypes.push(prototype.name.clone());
+            }
+        }
+        for old_prototype in &self.prototypes {
+            let Some(new_prototype) = newer.prototype(&old_prototype.name) else {
+                diff.removed_prototypes.push(old_prototype.name.clone());
+                continue;
+            };
+            for new_field in &new_prototype.fields {
+                match old_prototype.field(&new_field.name) {
+                    None => diff
+                        .added_fields
+                        .push(FieldChange::new(new_prototype, new_field)),
+                    Some(old_field) if old_field.type_id != new_field.type_id => {
+                        diff.retyped_fields.push(RetypedField {
+                            prototype: new_prototype.name.clone(),
+                            field: new_field.name.clone(),
+                            old_type_id: old_field.type_id,
+                            new_type_id: new_field.type_id,
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+            for old_field in &old_prototype.fields {
+                if new_prototype.field(&old_field.name).is_none() {
+                    diff.removed_fields
+                        .push(FieldChange::new(old_prototype, old_field));
+                }
+            }
+        }
+        diff
+    }
+
+    pub fn to_json(&self) -> String {
+        let prototypes = self
+            .prototypes
+            .iter()
+            .map(render_prototype_json)
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!("{{\n  \"prototypes\": [\n{prototypes}\n  ]\n}}\n")
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("# Schema\n");
+        for prototype in &self.prototypes {
+            output.push_str(&format!("\n## {}\n\n", prototype.name));
+            output.push_str("| Field | Type | Type ID | Enum values |\n");
+            output.push_str("| --- | --- | --- | --- |\n");
+            for field in &prototype.fields {
+                output.push_str(&format!(
+                    "| {} | {} | 0x{:02x} | {} |\n",
+                    field.name,
+                    field.type_name(),
+                    field.type_id,
+                    field
+                        .enum_values
+                        .as_ref()
+                        .map(render_enum_values_markdown)
+                        .unwrap_or_default()
+                ));
+            }
+        }
+        output
+    }
+}
+
+impl PrototypeSchema {
+    /// Look up a field by name.
+    pub fn field(&self, name: &str) -> Option<&FieldSchema> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl From<&Prototype<'_>> for PrototypeSchema {
+    fn from(prototype: &Prototype<'_>) -> Self {
+        Self {
+            name: prototype.name.to_string(),
+            fields: prototype.fields().map(FieldSchema::from).collect(),
+        }
+    }
+}
+
+impl FieldSchema {
+    /// Return a readable name for the field's type.
+    pub fn type_name(&self) -> &'static str {
+        type_name(self.type_id)
+    }
+}
+
+impl From<&ValuePrototype<'_>> for FieldSchema {
+    fn from(field: &ValuePrototype<'_>) -> Self {
+        Self {
+            name: field.name.to_string(),
+            type_id: field.type_id(),
+            enum_values: field.enum_values.as_ref().map(|values| {
+                values
+                    .iter()
+                    .map(|(value, name)| (*value, name.to_string()))
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl FieldChange {
+    fn new(prototype: &PrototypeSchema, field: &FieldSchema) -> Self {
+        Self {
+            prototype: prototype.name.clone(),
+            field: field.name.clone(),
+            type_id: field.type_id,
+        }
+    }
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_prototypes.is_empty()
+            && self.removed_prototypes.is_empty()
+            && self.added_fields.is_empty()
+            && self.removed_fields.is_empty()
+            && self.retyped_fields.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\n  \"added_prototypes\": {},\n  \"removed_prototypes\": {},\n  \"added_fields\": {},\n  \"removed_fields\": {},\n  \"retyped_fields\": {}\n}}\n",
+            render_string_iter(self.added_prototypes.iter().map(String::as_str)),
+            render_string_iter(self.removed_prototypes.iter().map(String::as_str)),
+            render_field_changes_json(&self.added_fields),
+            render_field_changes_json(&self.removed_fields),
+            render_retyped_fields_json(&self.retyped_fields),
+        )
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("# Schema changes\n");
+        if self.is_empty() {
+            output.push_str("\nNo changes.\n");
+            return output;
+        }
+        if !self.added_prototypes.is_empty() {
+            output.push_str("\n## Added prototypes\n\n");
+            for prototype in &self.added_prototypes {
+                output.push_str(&format!("- `{prototype}`\n"));
+            }
+        }
+        if !self.removed_prototypes.is_empty() {
+            output.push_str("\n## Removed prototypes\n\n");
+            for prototype in &self.removed_prototypes {
+                output.push_str(&format!("- `{prototype}`\n"));
+            }
+        }
+        if !self.added_fields.is_empty() {
+            output.push_str("\n## Added fields\n\n");
+            for change in &self.added_fields {
+                output.push_str(&format!(
+                    "- `{}.{}` ({})\n",
+                    change.prototype,
+                    change.field,
+                    type_name(change.type_id)
+                ));
+            }
+        }
+        if !self.removed_fields.is_empty() {
+            output.push_str("\n## Removed fields\n\n");
+            for change in &self.removed_fields {
+                output.push_str(&format!(
+                    "- `{}.{}` ({})\n",
+                    change.prototype,
+                    change.field,
+                    type_name(change.type_id)
+                ));
+            }
+        }
+        if !self.retyped_fields.is_empty() {
+            output.push_str("\n## Retyped fields\n\n");
+            for change in &self.retyped_fields {
+                output.push_str(&format!(
+                    "- `{}.{}`: {} -> {}\n",
+                    change.prototype,
+                    change.field,
+                    type_name(change.old_type_id),
+                    type_name(change.new_type_id)
+                ));
+            }
+        }
+        output
+    }
+}
+
+fn render_prototype_json(prototype: &PrototypeSchema) -> String {
+    let fields = prototype
+        .fields
+        .iter()
+        .map(|field| format!("        {}", render_field_json(field)))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!(
+        "    {{\n      \"name\": \"{}\",\n      \"fields\": [\n{fields}\n      ]\n    }}",
+        json_escape(&prototype.name)
+    )
+}
+
+fn render_field_json(field: &FieldSchema) -> String {
+    let enum_values = field
+        .enum_values
+        .as_ref()
+        .map(|values| {
+            let values = values
+                .iter()
+                .map(|(value, name)| format!("\"{value}\": \"{}\"", json_escape(name)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(", \"enum_values\": {{{values}}}")
+        })
+        .unwrap_or_default();
+    format!(
+        "{{ \"name\": \"{}\", \"type\": \"{}\", \"type_id\": {}{enum_values} }}",
+        json_escape(&field.name),
+        field.type_name(),
+        field.type_id
+    )
+}
+
+fn render_enum_values_markdown(values: &BTreeMap<u32, String>) -> String {
+    values
+        .iter()
+        .map(|(value, name)| format!("{value} = {name}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_field_changes_json(changes: &[FieldChange]) -> String {
+    let changes = changes
+        .iter()
+        .map(|change| {
+            format!(
+                "{{ \"prototype\": \"{}\", \"field\": \"{}\", \"type\": \"{}\" }}",
+                json_escape(&change.prototype),
+                json_escape(&change.field),
+                type_name(change.type_id)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("[{changes}]")
+}
+
+fn render_retyped_fields_json(changes: &[RetypedField]) -> String {
+    let changes = changes
+        .iter()
+        .map(|change| {
+            format!(
+                "{{ \"prototype\": \"{}\", \"field\": \"{}\", \"old_type\": \"{}\", \"new_type\": \"{}\" }}",
+                json_escape(&change.prototype),
+                json_escape(&change.field),
+                type_name(change.old_type_id),
+                type_name(change.new_type_id)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("[{changes}]")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn exports_prototypes_by_id() {
+        let schema = Schema::from_bsii(&bsii(vec![
+            prototype(2, "delivery_log_entry", vec![field(0x02, "params")]),
+            prototype(
+                1,
+                "player",
+                vec![field(0x25, "money"), enum_field("state", &[(0, "idle")])],
+            ),
+        ]));
+
+        assert_eq!(schema.prototypes[0].name, "player");
+        assert_eq!(schema.prototypes[1].name, "delivery_log_entry");
+        let money = schema.prototype("player").unwrap().field("money").unwrap();
+        assert_eq!(money.type_name(), "int32");
+        assert_eq!(money.enum_values, None);
+
+        let json = schema.to_json();
+        assert!(json.contains("{ \"name\": \"money\", \"type\": \"int32\", \"type_id\": 37 }"));
+        assert!(json.contains("\"enum_values\": {\"0\": \"idle\"}"));
+
+        let markdown = schema.to_markdown();
+        assert!(markdown.contains("## player"));
+        assert!(markdown.contains("| state | enum | 0x37 | 0 = idle |"));
+    }
+
+    #[test]
+    fn compares_schemas_by_name() {
+        let old = Schema::from_bsii(&bsii(vec![
+            prototype(
+                1,
+                "player",
+                vec![field(0x25, "money"), field(0x35, "hidden")],
+            ),
+            prototype(2, "removed_unit", vec![]),
+        ]));
+        let new = Schema::from_bsii(&bsii(vec![
+            prototype(1, "added_unit", vec![]),
+            prototype(
+                2,
+                "player",
+                vec![field(0x31, "money"), field(0x03, "hq_city")],
+            ),
+        ]));
+
+        let diff = old.compare(&new);
+
+        assert_eq!(diff.added_prototypes, ["added_unit"]);
+        assert_eq!(diff.removed_prototypes, ["removed_unit"]);
+        assert_eq!(diff.added_fields[0].field, "hq_city");
+        assert_eq!(diff.removed_fields[0].field, "hidden");
+        assert_eq!(
+            diff.retyped_fields,
+            [RetypedField {
+                prototype: "player".to_string(),
+                field: "money".to_string(),
+                old_type_id: 0x25,
+                new_type_id: 0x31,
+            }]
+        );
+        assert!(diff
+            .to_markdown()
+            .contains("- `player.money`: int32 -> int64"));
+        assert!(old.compare(&old).is_empty());
+    }
+
+    fn bsii(prototypes: Vec<Prototype<'static>>) -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from_iter(
+                prototypes
+                    .into_iter()
+                    .map(|prototype| (prototype.id, prototype)),
+            ),
+            data_blocks: vec![],
+        }
+    }
+
+    fn prototype(
+        id: u32,
+        name: &'static str,
+        value_prototypes: Vec<ValuePrototype<'static>>,
+    ) -> Prototype<'static> {
+        Prototype {
+            id,
+            name,
+            value_prototypes,
+        }
+    }
+
+    fn field(type_id: u32, name: &'static str) -> ValuePrototype<'static> {
+        ValuePrototype {
+            type_id,
+            name,
+            enum_values: None,
+        }
+    }
+
+    fn enum_field(name: &'static str, values: &[(u32, &'static str)]) -> ValuePrototype<'static> {
+        ValuePrototype {
+            type_id: 0x37,
+            name,
+            enum_values: Some(values.iter().copied().collect()),
+        }
+    }
+}
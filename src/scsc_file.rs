@@ -3,11 +3,15 @@
 //! References:
 //! <https://github.com/TheLazyTomcat/SII_Decrypt/blob/master/Source/SII_Decrypt_Decryptor.pas>
 //! <https://gitlab.com/jammerxd/sii-decryptsharp/-/blob/main/SIIDecryptSharp/SIIDecryptSharp/Decryptor.cs>
-use std::io::{self, Read};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::io::{self, Read, Write};
 
-use aes::cipher::block_padding::{NoPadding, UnpadError};
-use aes::cipher::{BlockDecryptMut, KeyIvInit};
+use aes::cipher::block_padding::{NoPadding, Pkcs7, UnpadError};
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
 use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
 
 /// Structure of a ScsC file
 /// ScsC file is a binary file that contains encrypted and compressed data.
@@ -16,14 +20,18 @@ use flate2::read::ZlibDecoder;
 /// After decryption and decompression, the data might be in BSII format (binary
 /// form) or SIIN format (textual form).
 pub struct ScscFile<'a> {
+    // The header and HMAC are checked by the parser but not needed to decode.
+    #[allow(dead_code)]
     pub(crate) header: &'a [u8], // ScsC, size 4
-    pub(crate) hmac: &'a [u8],   // size 32
-    pub(crate) iv: &'a [u8],     // size 16
+    #[allow(dead_code)]
+    pub(crate) hmac: &'a [u8], // size 32
+    pub(crate) iv: &'a [u8], // size 16
     pub(crate) size: u32,
     pub data: &'a [u8],
 }
 
 type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
 
 #[derive(Debug)]
 pub enum ParseError {
@@ -81,9 +89,61 @@ impl ScscFile<'_> {
     }
 }
 
+/// Compresses and encrypts a payload (BSII or SiiN data) into a ScsC file.
+///
+/// A fresh IV is chosen for every call. The HMAC key used by the game is not
+/// known, so the HMAC field is zero-filled; decoders (including this crate) do
+/// not verify it.
+pub fn encode(payload: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing into a `Vec` cannot fail
+    encoder.write_all(payload).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let iv = random_iv();
+    let cipher = Aes256CbcEnc::new_from_slices(ENCRYPTION_KEY, &iv).unwrap();
+    let mut encrypted = vec![0; (compressed.len() / 16 + 1) * 16];
+    // The buffer always has room for the padding
+    cipher
+        .encrypt_padded_b2b_mut::<Pkcs7>(&compressed, &mut encrypted)
+        .unwrap();
+
+    let mut output = Vec::with_capacity(4 + 32 + 16 + 4 + encrypted.len());
+    output.extend_from_slice(b"ScsC");
+    output.extend_from_slice(&[0; 32]);
+    output.extend_from_slice(&iv);
+    output.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    output.extend_from_slice(&encrypted);
+    output
+}
+
+fn random_iv() -> [u8; 16] {
+    // The IV only needs to differ between files, so the randomly seeded std
+    // hasher is good enough and avoids pulling in a random number generator.
+    let state = RandomState::new();
+    let mut iv = [0; 16];
+    iv[..8].copy_from_slice(&state.hash_one(0u8).to_le_bytes());
+    iv[8..].copy_from_slice(&state.hash_one(1u8).to_le_bytes());
+    iv
+}
+
 const ENCRYPTION_KEY: &[u8; 32] = &[
     0x2a, 0x5f, 0xcb, 0x17, 0x91, 0xd2, 0x2f, 0xb6, 0x02, 0x45, 0xb3, 0xd8, 0x36, 0x9e, 0xd0, 0xb2,
     0xc2, 0x73, 0x71, 0x56, 0x3f, 0xbf, 0x1f, 0x3c, 0x9e, 0xdf, 0x6b, 0x11, 0x82, 0x5a, 0x5d, 0x0a,
 ];
 
-// TODO: Add tests for decode
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_reverses_encode() {
+        let payload = b"SiiNunit\n{\n}\n";
+        let encoded = encode(payload);
+
+        let scsc_file = ScscFile::parse(&encoded).unwrap();
+
+        assert_eq!(scsc_file.header, b"ScsC");
+        assert_eq!(scsc_file.decode().unwrap(), payload);
+    }
+}
@@ -0,0 +1,194 @@
+//! Searches the values of all fields in a BSII file.
+
+use regex::Regex;
+
+use crate::bsii_file::{BsiiFile, DataValue, Id, ValuePrototype};
+use crate::bsii_output::placement_position;
+
+/// What to look for in field values.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Matches values whose plain text contains the string, see
+    /// `DataValue::to_plain_text`.
+    Text(String),
+    /// Matches values whose text matches the regular expression.
+    Regex(Regex),
+    /// Matches numeric values equal to the number, including components of
+    /// vectors and the raw value of enums.
+    Number(f64),
+}
+
+/// A field value matching a pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    pub unit: &'a Id,
+    pub prototype: &'a str,
+    pub field: &'a str,
+    /// The index of the element, if the field is an array.
+    pub index: Option<usize>,
+    /// The matching value as it appears in a SIIN file.
+    pub value: String,
+}
+
+impl Pattern {
+    /// Compile a regular expression pattern.
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Pattern::Regex)
+    }
+
+    fn matches(&self, value: &DataValue<'_>, value_prototype: &ValuePrototype<'_>) -> bool {
+        match self {
+            Pattern::Text(text) => value.to_plain_text(value_prototype).contains(text.as_str()),
+            Pattern::Regex(regex) => regex.is_match(&value.to_plain_text(value_prototype)),
+            Pattern::Number(number) => has_number(value, *number),
+        }
+    }
+}
+
+/// Find all field values in the file matching the pattern, in file order.
+///
+/// Arrays are searched element by element.
+pub fn search<'a>(bsii: &'a BsiiFile<'_>, pattern: &Pattern) -> Vec<SearchHit<'a>> {
+    let mut hits = Vec::new();
+    for data_block in &bsii.data_blocks {
+        let Some(prototype) = bsii.get_prototype(data_block.prototype_id) else {
+            continue;
+        };
+        for (value, value_prototype) in data_block.data.iter().zip(&prototype.value_prototypes) {
+            let is_array = value.is_array();
+            for (index, element) in value.elements().iter().enumerate() {
+                if pattern.matches(element, value_prototype) {
+                    hits.push(SearchHit {
+                        unit: &data_block.id,
+                        prototype: prototype.name,
+                        field: value_prototype.name,
+                        index: is_array.then_some(index),
+                        value: element.to_siin_value(value_prototype),
+                    });
+                }
+            }
+        }
+    }
+    hits
+}
+
+/// Whether the value contains the number. Floats are compared at their own
+/// precision, so that `1.1` matches the stored `1.1f32`.
+fn has_number(value: &DataValue<'_>, number: f64) -> bool {
+    let floats = |floats: &[f32]| floats.contains(&(number as f32));
+    match value {
+        DataValue::Float(f) => floats(&[*f]),
+        DataValue::FloatVec2((f1, f2)) => floats(&[*f1, *f2]),
+        DataValue::FloatVec3((f1, f2, f3)) => floats(&[*f1, *f2, *f3]),
+        DataValue::FloatVec4((f1, f2, f3, f4)) => floats(&[*f1, *f2, *f3, *f4]),
+        DataValue::FloatVec8(placement) => {
+            let (x, y, z) = placement_position(placement);
+            let (.., w, rx, ry, rz) = *placement;
+            floats(&[x, y, z, w, rx, ry, rz])
+        }
+        DataValue::Int32Vec3((i1, i2, i3)) => [*i1, *i2, *i3].map(f64::from).contains(&number),
+        DataValue::Int32(i) => f64::from(*i) == number,
+        DataValue::UInt32(u) | DataValue::Enum(u) => f64::from(*u) == number,
+        DataValue::UInt16(u) => f64::from(*u) == number,
+        DataValue::Int64(i) => *i as f64 == number,
+        DataValue::UInt64(u) => *u as f64 == number,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::bsii_file::{DataBlock, Prototype};
+
+    fn sample() -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "job_offer_data",
+                    value_prototypes: vec![
+                        ValuePrototype {
+                            type_id: 0x01,
+                            name: "target",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x04,
+                            name: "cargos",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x37,
+                            name: "urgency",
+                            enum_values: Some(HashMap::from([(0, "standard"), (2, "urgent")])),
+                        },
+                        ValuePrototype {
+                            type_id: 0x28,
+                            name: "distances",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x05,
+                            name: "cargo_mass",
+                            enum_values: None,
+                        },
+                    ],
+                },
+            )]),
+            data_blocks: vec![DataBlock {
+                prototype_id: 1,
+                id: Id::Nameless(0x1234),
+                data: vec![
+                    DataValue::String("stokes.berlin"),
+                    DataValue::EncodedStringArray(vec!["apples".to_string(), "gravel".to_string()]),
+                    DataValue::Enum(2),
+                    DataValue::UInt32Array(vec![362, 2]),
+                    DataValue::Float(1.1),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn searches_text_in_scalars_arrays_and_enums() {
+        let bsii = sample();
+
+        let hits = search(&bsii, &Pattern::Text("gravel".to_string()));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].unit, &Id::Nameless(0x1234));
+        assert_eq!(hits[0].prototype, "job_offer_data");
+        assert_eq!(hits[0].field, "cargos");
+        assert_eq!(hits[0].index, Some(1));
+
+        let hits = search(&bsii, &Pattern::Text("urgent".to_string()));
+        assert_eq!((hits[0].field, hits[0].index), ("urgency", None));
+        assert_eq!(hits[0].value, "urgent");
+    }
+
+    #[test]
+    fn searches_with_regex_and_numbers() {
+        let bsii = sample();
+
+        let hits = search(&bsii, &Pattern::regex(r"^stokes\.").unwrap());
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].value, "\"stokes.berlin\"");
+
+        let hits = search(&bsii, &Pattern::Number(2.0));
+        assert_eq!(
+            hits.iter()
+                .map(|hit| (hit.field, hit.index))
+                .collect::<Vec<_>>(),
+            [("urgency", None), ("distances", Some(1))]
+        );
+
+        let hits = search(&bsii, &Pattern::Number(1.1));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].field, "cargo_mass");
+    }
+}
@@ -0,0 +1,626 @@
+//! Parses textual SII (SiiN) files and converts them to BSII.
+//!
+//! Textual files do not carry the prototypes of the binary format, so the type
+//! of every field is inferred from the values of all units of the same class.
+//! The inference is best effort, since some types share a textual form:
+//!
+//! - Tokens and strings that only contain token characters are both written
+//!   unquoted, so they become tokens. Enum values are written by name and
+//!   therefore also become tokens or strings.
+//! - IDs with a single part are indistinguishable from tokens.
+//! - Floats with integral values are written like integers, so a field only
+//!   becomes a float if at least one value is not integral.
+//! - Arrays that are empty in every unit are written like an integer field
+//!   holding the length `0`.
+//!
+//! Reference: <https://modding.scssoft.com/wiki/Documentation/Engine/Units>
+
+use std::collections::HashMap;
+
+use crate::bsii_file::{BsiiFile, DataBlock, DataValue, Id, Prototype, ValuePrototype};
+use crate::bsii_write::encode_token;
+use crate::siin_value::{collect_array, default_value, parse_scalar};
+
+#[derive(Debug)]
+pub enum ParseError {
+    InvalidHeader,
+    InvalidLine { line: usize, message: String },
+    UnexpectedEnd,
+    InvalidValue { field: String, value: String },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidHeader => write!(f, "Invalid header"),
+            ParseError::InvalidLine { line, message } => write!(f, "Line {}: {}", line, message),
+            ParseError::UnexpectedEnd => write!(f, "Unexpected end of file"),
+            ParseError::InvalidValue { field, value } => {
+                write!(f, "Invalid value {:?} of field {}", value, field)
+            }
+        }
+    }
+}
+
+/// A parsed SiiN file.
+pub(crate) struct SiinDocument {
+    units: Vec<SiinUnit>,
+}
+
+struct SiinUnit {
+    class: String,
+    id: Id,
+    fields: Vec<SiinField>,
+}
+
+struct SiinField {
+    name: String,
+    value: SiinValue,
+}
+
+enum SiinValue {
+    Scalar(SiinScalar),
+    Array(Vec<SiinScalar>),
+}
+
+/// A single value, with quotes and escape sequences already removed.
+struct SiinScalar {
+    text: String,
+    quoted: bool,
+}
+
+enum State {
+    Start,
+    Header,
+    Root,
+    UnitOpening(SiinUnit),
+    Unit(SiinUnit),
+    End,
+}
+
+impl SiinDocument {
+    pub(crate) fn parse(content: &[u8]) -> Result<Self, ParseError> {
+        let content = String::from_utf8_lossy(content);
+        let mut units = Vec::new();
+        let mut state = State::Start;
+        for (index, line) in content.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = |message: &str| ParseError::InvalidLine {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            state = match state {
+                State::Start if line == "SiiNunit" => State::Header,
+                State::Start => return Err(ParseError::InvalidHeader),
+                State::Header if line == "{" => State::Root,
+                State::Header => return Err(invalid("expected `{`")),
+                State::Root if line == "}" => State::End,
+                State::Root => {
+                    let (header, opened) = match line.strip_suffix('{') {
+                        Some(header) => (header, true),
+                        None => (line, false),
+                    };
+                    let (class, id) = header
+                        .split_once(':')
+                        .ok_or_else(|| invalid("expected `class : id`"))?;
+                    let unit = SiinUnit {
+                        class: class.trim().to_string(),
+                        id: Id::parse(id.trim()).ok_or_else(|| invalid("invalid unit ID"))?,
+                        fields: vec![],
+                    };
+                    if opened {
+                        State::Unit(unit)
+                    } else {
+                        State::UnitOpening(unit)
+                    }
+                }
+                State::UnitOpening(unit) if line == "{" => State::Unit(unit),
+                State::UnitOpening(_) => return Err(invalid("expected `{`")),
+                State::Unit(unit) if line == "}" => {
+                    units.push(unit);
+                    State::Root
+                }
+                State::Unit(mut unit) => {
+                    let (name, value) = line
+                        .split_once(':')
+                        .ok_or_else(|| invalid("expected `field: value`"))?;
+                    let value = parse_text(value.trim()).ok_or_else(|| invalid("invalid value"))?;
+                    unit.add_field(name.trim(), value).map_err(&invalid)?;
+                    State::Unit(unit)
+                }
+                State::End => return Err(invalid("unexpected content after the end")),
+            };
+        }
+        match state {
+            State::End => Ok(Self { units }),
+            State::Start => Err(ParseError::InvalidHeader),
+            _ => Err(ParseError::UnexpectedEnd),
+        }
+    }
+
+    /// Convert the document into a BSII file, inferring the field types.
+    pub(crate) fn to_bsii(&self) -> Result<BsiiFile<'_>, ParseError> {
+        let mut prototypes: Vec<Prototype<'_>> = Vec::new();
+        let mut prototype_ids = HashMap::new();
+        for unit in &self.units {
+            if prototype_ids.contains_key(unit.class.as_str()) {
+                continue;
+            }
+            let id = prototypes.len() as u32 + 1;
+            prototype_ids.insert(unit.class.as_str(), id);
+            prototypes.push(self.infer_prototype(id, &unit.class));
+        }
+
+        let data_blocks = self
+            .units
+            .iter()
+            .map(|unit| {
+                let prototype_id = prototype_ids[unit.class.as_str()];
+                let prototype = &prototypes[prototype_id as usize - 1];
+                Ok(DataBlock {
+                    prototype_id,
+                    id: unit.id.clone(),
+                    data: prototype
+                        .value_prototypes
+                        .iter()
+                        .map(|value_prototype| unit.value(value_prototype))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: prototypes
+                .into_iter()
+                .map(|prototype| (prototype.id, prototype))
+                .collect(),
+            data_blocks,
+        })
+    }
+
+    fn infer_prototype<'a>(&'a self, id: u32, class: &'a str) -> Prototype<'a> {
+        let units = self
+            .units
+            .iter()
+            .filter(|unit| unit.class == class)
+            .collect::<Vec<_>>();
+        let mut names: Vec<&str> = Vec::new();
+        for unit in &units {
+            for field in &unit.fields {
+                if !names.contains(&field.name.as_str()) {
+                    names.push(&field.name);
+                }
+            }
+        }
+        let value_prototypes = names
+            .into_iter()
+            .map(|name| {
+                let values = units
+                    .iter()
+                    .filter_map(|unit| unit.field(name))
+                    .collect::<Vec<_>>();
+                ValuePrototype {
+                    type_id: infer_type_id(&values),
+                    name,
+                    enum_values: None,
+                }
+            })
+            .collect();
+        Prototype {
+            id,
+            name: class,
+            value_prototypes,
+        }
+    }
+}
+
+impl SiinUnit {
+    fn add_field(&mut self, name: &str, value: SiinScalar) -> Result<(), &'static str> {
+        let Some((name, index)) = name.strip_suffix(']').and_then(|name| name.split_once('['))
+        else {
+            self.fields.push(SiinField {
+                name: name.to_string(),
+                value: SiinValue::Scalar(value),
+            });
+            return Ok(());
+        };
+        if !index.is_empty() && index.parse::<usize>().is_err() {
+            return Err("invalid array index");
+        }
+        match self.fields.iter_mut().find(|field| field.name == name) {
+            Some(field) => match &mut field.value {
+                SiinValue::Array(values) => values.push(value),
+                // The array length precedes the elements
+                SiinValue::Scalar(_) => field.value = SiinValue::Array(vec![value]),
+            },
+            None => self.fields.push(SiinField {
+                name: name.to_string(),
+                value: SiinValue::Array(vec![value]),
+            }),
+        }
+        Ok(())
+    }
+
+    fn field(&self, name: &str) -> Option<&SiinValue> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| &field.value)
+    }
+
+    fn value<'a>(
+        &'a self,
+        value_prototype: &ValuePrototype<'_>,
+    ) -> Result<DataValue<'a>, ParseError> {
+        let type_id = value_prototype.type_id;
+        let scalar = |type_id, value: &'a SiinScalar| {
+            scalar_value(type_id, value).ok_or_else(|| ParseError::InvalidValue {
+                field: value_prototype.name.to_string(),
+                value: value.text.clone(),
+            })
+        };
+        let value = match self.field(value_prototype.name) {
+            Some(SiinValue::Array(values)) => collect_array(
+                type_id,
+                values
+                    .iter()
+                    .map(|value| scalar(type_id - 1, value))
+                    .collect::<Result<_, _>>()?,
+            ),
+            // An array that is empty in this unit only has its length written
+            Some(SiinValue::Scalar(_)) if is_array_type(type_id) => None,
+            Some(SiinValue::Scalar(value)) => Some(scalar(type_id, value)?),
+            None => None,
+        };
+        // Inferred types accept all values, so this only applies to missing
+        // fields and empty arrays
+        Ok(value.unwrap_or_else(|| default_value(type_id).unwrap()))
+    }
+}
+
+fn scalar_value(type_id: u32, value: &SiinScalar) -> Option<DataValue<'_>> {
+    match type_id {
+        0x01 => Some(DataValue::String(&value.text)),
+        _ => parse_scalar(type_id, None, &value.text),
+    }
+}
+
+fn is_array_type(type_id: u32) -> bool {
+    crate::siin_value::array_element_type_id(type_id).is_some()
+}
+
+/// The textual form of a single value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Bool,
+    Nil,
+    Int(i128),
+    Float,
+    Float2,
+    Float3,
+    Float4,
+    Placement,
+    Id,
+    Token,
+    String,
+}
+
+fn classify(value: &SiinScalar) -> Kind {
+    let text = value.text.as_str();
+    if value.quoted {
+        return Kind::String;
+    }
+    if text == "true" || text == "false" {
+        return Kind::Bool;
+    }
+    if text == "nil" {
+        return Kind::Nil;
+    }
+    if let Ok(int) = text.parse::<i128>() {
+        return Kind::Int(int);
+    }
+    if text.starts_with('&') || text.parse::<f32>().is_ok() {
+        if parse_scalar(0x05, None, text).is_some() {
+            return Kind::Float;
+        }
+        return Kind::String;
+    }
+    if text.starts_with('(') {
+        for (type_id, kind) in [
+            (0x07, Kind::Float2),
+            (0x09, Kind::Float3),
+            (0x17, Kind::Float4),
+            (0x19, Kind::Placement),
+        ] {
+            if parse_scalar(type_id, None, text).is_some() {
+                // vec4 and vec3 only differ in the separator after the first value
+                if kind == Kind::Float4 && !text.contains(';') {
+                    continue;
+                }
+                return kind;
+            }
+        }
+        return Kind::String;
+    }
+    if encode_token(text).is_some() {
+        return Kind::Token;
+    }
+    if Id::parse(text).is_some() {
+        return Kind::Id;
+    }
+    Kind::String
+}
+
+fn infer_type_id(values: &[&SiinValue]) -> u32 {
+    let is_array = values
+        .iter()
+        .any(|value| matches!(value, SiinValue::Array(_)));
+    let kinds = values
+        .iter()
+        .flat_map(|value| match value {
+            SiinValue::Array(values) => values.iter().collect::<Vec<_>>(),
+            SiinValue::Scalar(_) if is_array => vec![],
+            SiinValue::Scalar(value) => vec![value],
+        })
+        .map(classify)
+        .collect::<Vec<_>>();
+    let type_id = infer_scalar_type_id(&kinds);
+    if is_array {
+        match type_id {
+            // There are no arrays of float2
+            0x07 => 0x02,
+            0x39 => 0x3a,
+            _ => type_id + 1,
+        }
+    } else {
+        type_id
+    }
+}
+
+fn infer_scalar_type_id(kinds: &[Kind]) -> u32 {
+    let all = |predicate: fn(&Kind) -> bool| kinds.iter().all(predicate);
+    let any = |predicate: fn(&Kind) -> bool| kinds.iter().any(predicate);
+    if kinds.is_empty() {
+        return 0x01;
+    }
+    if all(|kind| *kind == Kind::Bool) {
+        return 0x35;
+    }
+    if all(|kind| matches!(kind, Kind::Int(_) | Kind::Nil | Kind::Float)) {
+        let has_nil = any(|kind| *kind == Kind::Nil);
+        if any(|kind| *kind == Kind::Float) {
+            return if has_nil { 0x01 } else { 0x05 };
+        }
+        let ints = kinds.iter().filter_map(|kind| match kind {
+            Kind::Int(int) => Some(*int),
+            _ => None,
+        });
+        let min = ints.clone().min().unwrap_or(0);
+        let max = ints.max().unwrap_or(0);
+        let fits = |low: i128, high: i128| min >= low && max <= high;
+        return if has_nil {
+            // `nil` is the maximum value of unsigned types
+            if fits(0, i128::from(u32::MAX) - 1) {
+                0x27
+            } else if fits(0, i128::from(u64::MAX) - 1) {
+                0x33
+            } else {
+                0x01
+            }
+        } else if fits(i128::from(i32::MIN), i128::from(i32::MAX)) {
+            0x25
+        } else if fits(0, i128::from(u32::MAX)) {
+            0x27
+        } else if fits(i128::from(i64::MIN), i128::from(i64::MAX)) {
+            0x31
+        } else if fits(0, i128::from(u64::MAX)) {
+            0x33
+        } else {
+            0x01
+        };
+    }
+    for (kind, type_id) in [
+        (Kind::Float2, 0x07),
+        (Kind::Float3, 0x09),
+        (Kind::Float4, 0x17),
+        (Kind::Placement, 0x19),
+    ] {
+        if kinds.iter().all(|other| *other == kind) {
+            return type_id;
+        }
+    }
+    if all(|kind| matches!(kind, Kind::Token | Kind::Id)) {
+        return if any(|kind| *kind == Kind::Id) {
+            0x39
+        } else {
+            0x03
+        };
+    }
+    0x01
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = None;
+    for (index, ch) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            previous = Some(ch);
+            continue;
+        }
+        match ch {
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            '/' if !in_string && previous == Some('/') => return &line[..index - 1],
+            _ => {}
+        }
+        previous = Some(ch);
+    }
+    line
+}
+
+/// Parse the textual form of a value, removing quotes and escape sequences.
+fn parse_text(text: &str) -> Option<SiinScalar> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        return Some(SiinScalar {
+            text: text.to_string(),
+            quoted: false,
+        });
+    };
+    let quoted = quoted.strip_suffix('"')?;
+    let mut bytes = Vec::new();
+    let mut chars = quoted.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next()? {
+            'x' => {
+                let hex = chars.by_ref().take(2).collect::<String>();
+                bytes.push(u8::from_str_radix(&hex, 16).ok()?);
+            }
+            'n' => bytes.push(b'\n'),
+            't' => bytes.push(b'\t'),
+            ch => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    Some(SiinScalar {
+        text: String::from_utf8(bytes).ok()?,
+        quoted: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAVE: &str = r#"SiiNunit
+{
+economy : _nameless.1a2.3b4c {
+ bank: _nameless.1a2.3b4d
+ game_time: 12345
+ experience_points: nil
+ visited_cities: 2
+ visited_cities[0]: berlin
+ visited_cities[1]: "Den Haag"
+ truck_placement: (100.5, 2, -30.25) (1; 0, 0, 0)
+ total_distance: &3fc00000
+ new_game: false
+}
+bank : _nameless.1a2.3b4d
+{
+ money_account: -5
+ loans: 0
+}
+bank : bank.second {
+ money_account: 3000000000 # comment
+ loans: 1
+ loans[0]: _nameless.1
+ note: "\x48i // there"
+}
+}
+"#;
+
+    #[test]
+    fn infers_types_from_values() {
+        let document = SiinDocument::parse(SAVE.as_bytes()).unwrap();
+        let bsii = document.to_bsii().unwrap();
+
+        let economy = bsii.get_prototype(1).unwrap();
+        assert_eq!(economy.name, "economy");
+        let types = economy
+            .fields()
+            .map(|field| (field.name, field.type_name()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                ("bank", "id"),
+                ("game_time", "int32"),
+                ("experience_points", "uint32"),
+                ("visited_cities", "string[]"),
+                ("truck_placement", "placement"),
+                ("total_distance", "float"),
+                ("new_game", "bool"),
+            ]
+        );
+
+        let bank = bsii.get_prototype(2).unwrap();
+        assert_eq!(bank.field("money_account").unwrap().type_name(), "int64");
+        assert_eq!(bank.field("loans").unwrap().type_name(), "id[]");
+        assert_eq!(bank.field("note").unwrap().type_name(), "string");
+
+        let first_bank = &bsii.data_blocks[1];
+        assert_eq!(
+            first_bank.field(&bsii, "loans"),
+            Some(&DataValue::IdArray(vec![]))
+        );
+        assert_eq!(
+            first_bank.field(&bsii, "note"),
+            Some(&DataValue::String(""))
+        );
+        let second_bank = &bsii.data_blocks[2];
+        assert_eq!(
+            second_bank.field(&bsii, "note"),
+            Some(&DataValue::String("Hi // there"))
+        );
+    }
+
+    #[test]
+    fn converted_file_round_trips_to_text() {
+        let document = SiinDocument::parse(SAVE.as_bytes()).unwrap();
+        let bytes = document.to_bsii().unwrap().to_bytes().unwrap();
+        let bsii = BsiiFile::parse(&bytes).unwrap();
+        let siin = bsii.to_siin();
+
+        assert!(siin.contains("economy : _nameless.1a2.3b4c {\n"));
+        assert!(siin.contains("  visited_cities[1]: \"Den Haag\"\n"));
+        assert!(siin.contains("  truck_placement: (&42c90000, 2, &c1f20000) (1; 0, 0, 0)\n"));
+        assert!(siin.contains("  money_account: 3000000000\n"));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(matches!(
+            SiinDocument::parse(b"BSII"),
+            Err(ParseError::InvalidHeader)
+        ));
+        assert!(matches!(
+            SiinDocument::parse(b"SiiNunit\n{\nunit : Invalid.Id {\n}\n}\n"),
+            Err(ParseError::InvalidLine { line: 3, .. })
+        ));
+        assert!(matches!(
+            SiinDocument::parse(b"SiiNunit\n{\nunit : unit.id {\n"),
+            Err(ParseError::UnexpectedEnd)
+        ));
+    }
+
+    #[test]
+    fn rejects_values_not_matching_the_field_type() {
+        let document = SiinDocument::parse(SAVE.as_bytes()).unwrap();
+        let unit = &document.units[0];
+        let value_prototype = ValuePrototype {
+            type_id: 0x28,
+            name: "visited_cities",
+            enum_values: None,
+        };
+
+        assert!(matches!(
+            unit.value(&value_prototype),
+            Err(ParseError::InvalidValue { field, value })
+                if field == "visited_cities" && value == "berlin"
+        ));
+    }
+}
@@ -0,0 +1,343 @@
+//! Parses single values written in the textual SII format.
+//!
+//! This is the inverse of the value formatting in `bsii_output`, driven by the
+//! BSII type ID of the field that the value belongs to.
+//! Reference: <https://modding.scssoft.com/wiki/Documentation/Engine/Units>
+
+use std::collections::HashMap;
+
+use crate::bsii_file::{DataValue, Id, Placement};
+use crate::bsii_write::encode_token;
+
+/// Return the type ID of the elements of an array type, or `None` if the type
+/// is not an array.
+pub(crate) fn array_element_type_id(type_id: u32) -> Option<u32> {
+    match type_id {
+        0x02 | 0x04 | 0x06 | 0x0a | 0x12 | 0x18 | 0x1a | 0x26 | 0x28 | 0x2c | 0x32 | 0x34
+        | 0x36 | 0x3a | 0x3c => Some(type_id - 1),
+        _ => None,
+    }
+}
+
+/// Parse a scalar value of the given type.
+///
+/// Strings may optionally be surrounded by quotes, escape sequences are not
+/// interpreted. Returns `None` if the text is not a valid value of the type.
+pub(crate) fn parse_scalar<'a>(
+    type_id: u32,
+    enum_values: Option<&HashMap<u32, &str>>,
+    text: &'a str,
+) -> Option<DataValue<'a>> {
+    let text = text.trim();
+    let value = match type_id {
+        0x01 => DataValue::String(unquote(text)),
+        0x03 => DataValue::EncodedString(parse_token(unquote(text))?),
+        0x05 => DataValue::Float(parse_float(text)?),
+        0x07 => {
+            let [f1, f2] = parse_tuple(text, parse_float)?;
+            DataValue::FloatVec2((f1, f2))
+        }
+        0x09 => {
+            let [f1, f2, f3] = parse_tuple(text, parse_float)?;
+            DataValue::FloatVec3((f1, f2, f3))
+        }
+        0x11 => {
+            let [i1, i2, i3] = parse_tuple(text, |i| i.parse().ok())?;
+            DataValue::Int32Vec3((i1, i2, i3))
+        }
+        0x17 => DataValue::FloatVec4(parse_float_vec4(text)?),
+        0x19 => DataValue::FloatVec8(parse_placement(text)?),
+        0x25 => DataValue::Int32(text.parse().ok()?),
+        0x27 | 0x2f => DataValue::UInt32(parse_nillable(text, u32::MAX)?),
+        0x2b => DataValue::UInt16(parse_nillable(text, u16::MAX)?),
+        0x31 => DataValue::Int64(text.parse().ok()?),
+        0x33 => DataValue::UInt64(parse_nillable(text, u64::MAX)?),
+        0x35 => DataValue::Bool(text.parse().ok()?),
+        0x37 => {
+            let name = unquote(text);
+            let enum_values = enum_values?;
+            let value = enum_values
+                .iter()
+                .find(|(_, value_name)| **value_name == name)
+                .map(|(value, _)| *value)
+                .or_else(|| {
+                    text.parse()
+                        .ok()
+                        .filter(|value| enum_values.contains_key(value))
+                })?;
+            DataValue::Enum(value)
+        }
+        0x39 | 0x3b | 0x3d => DataValue::Id(Id::parse(text)?),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Replace an element of an array value with a scalar value of the element type.
+///
+/// Returns `false` if the index is out of bounds or the types do not match.
+pub(crate) fn set_array_element<'a>(
+    array: &mut DataValue<'a>,
+    index: usize,
+    element: DataValue<'a>,
+) -> bool {
+    fn set<T>(values: &mut [T], index: usize, value: T) -> bool {
+        match values.get_mut(index) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    match (array, element) {
+        (DataValue::StringArray(values), DataValue::String(value)) => set(values, index, value),
+        (DataValue::EncodedStringArray(values), DataValue::EncodedString(value)) => {
+            set(values, index, value)
+        }
+        (DataValue::FloatArray(values), DataValue::Float(value)) => set(values, index, value),
+        (DataValue::FloatVec3Array(values), DataValue::FloatVec3(value)) => {
+            set(values, index, value)
+        }
+        (DataValue::Int32Vec3Array(values), DataValue::Int32Vec3(value)) => {
+            set(values, index, value)
+        }
+        (DataValue::FloatVec4Array(values), DataValue::FloatVec4(value)) => {
+            set(values, index, value)
+        }
+        (DataValue::FloatVec8Array(values), DataValue::FloatVec8(value)) => {
+            set(values, index, value)
+        }
+        (DataValue::Int32Array(values), DataValue::Int32(value)) => set(values, index, value),
+        (DataValue::UInt32Array(values), DataValue::UInt32(value)) => set(values, index, value),
+        (DataValue::UInt16Array(values), DataValue::UInt16(value)) => set(values, index, value),
+        (DataValue::Int64Array(values), DataValue::Int64(value)) => set(values, index, value),
+        (DataValue::UInt64Array(values), DataValue::UInt64(value)) => set(values, index, value),
+        (DataValue::BoolArray(values), DataValue::Bool(value)) => set(values, index, value),
+        (DataValue::IdArray(values), DataValue::Id(value)) => set(values, index, value),
+        _ => false,
+    }
+}
+
+/// Build an array value of the given array type from scalar element values.
+///
+/// Returns `None` if an element does not match the array's element type.
+pub(crate) fn collect_array<'a>(
+    type_id: u32,
+    elements: Vec<DataValue<'a>>,
+) -> Option<DataValue<'a>> {
+    macro_rules! collect {
+        ($array:ident, $scalar:ident) => {
+            elements
+                .into_iter()
+                .map(|element| match element {
+                    DataValue::$scalar(value) => Some(value),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(DataValue::$array)
+        };
+    }
+
+    match type_id {
+        0x02 => collect!(StringArray, String),
+        0x04 => collect!(EncodedStringArray, EncodedString),
+        0x06 => collect!(FloatArray, Float),
+        0x0a => collect!(FloatVec3Array, FloatVec3),
+        0x12 => collect!(Int32Vec3Array, Int32Vec3),
+        0x18 => collect!(FloatVec4Array, FloatVec4),
+        0x1a => collect!(FloatVec8Array, FloatVec8),
+        0x26 => collect!(Int32Array, Int32),
+        0x28 => collect!(UInt32Array, UInt32),
+        0x2c => collect!(UInt16Array, UInt16),
+        0x32 => collect!(Int64Array, Int64),
+        0x34 => collect!(UInt64Array, UInt64),
+        0x36 => collect!(BoolArray, Bool),
+        0x3a | 0x3c => collect!(IdArray, Id),
+        _ => None,
+    }
+}
+
+/// Return the zero value of a type, used for fields missing from a unit.
+pub(crate) fn default_value(type_id: u32) -> Option<DataValue<'static>> {
+    if array_element_type_id(type_id).is_some() {
+        return collect_array(type_id, vec![]);
+    }
+    let value = match type_id {
+        0x01 => DataValue::String(""),
+        0x03 => DataValue::EncodedString(String::new()),
+        0x05 => DataValue::Float(0.0),
+        0x07 => DataValue::FloatVec2((0.0, 0.0)),
+        0x09 => DataValue::FloatVec3((0.0, 0.0, 0.0)),
+        0x11 => DataValue::Int32Vec3((0, 0, 0)),
+        0x17 => DataValue::FloatVec4((0.0, 0.0, 0.0, 0.0)),
+        0x19 => DataValue::FloatVec8(parse_placement("(0, 0, 0) (1; 0, 0, 0)")?),
+        0x25 => DataValue::Int32(0),
+        0x27 | 0x2f => DataValue::UInt32(0),
+        0x2b => DataValue::UInt16(0),
+        0x31 => DataValue::Int64(0),
+        0x33 => DataValue::UInt64(0),
+        0x35 => DataValue::Bool(false),
+        0x39 | 0x3b | 0x3d => DataValue::Id(Id::Named(vec![])),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+fn parse_token(text: &str) -> Option<String> {
+    encode_token(text)?;
+    Some(text.to_string())
+}
+
+fn parse_float(text: &str) -> Option<f32> {
+    // Floats that cannot be represented exactly are written as `&` followed by
+    // the hexadecimal bit pattern
+    match text.trim().strip_prefix('&') {
+        Some(bits) => u32::from_str_radix(bits, 16).ok().map(f32::from_bits),
+        None => text.trim().parse().ok(),
+    }
+}
+
+fn parse_nillable<T: std::str::FromStr>(text: &str, nil: T) -> Option<T> {
+    if text == "nil" {
+        Some(nil)
+    } else {
+        text.parse().ok()
+    }
+}
+
+fn parse_tuple<T, const N: usize>(
+    text: &str,
+    parse_fn: impl Fn(&str) -> Option<T>,
+) -> Option<[T; N]> {
+    let inner = text.trim().strip_prefix('(')?.strip_suffix(')')?;
+    let values = inner
+        .split([',', ';'])
+        .map(|value| parse_fn(value.trim()))
+        .collect::<Option<Vec<_>>>()?;
+    values.try_into().ok()
+}
+
+fn parse_float_vec4(text: &str) -> Option<(f32, f32, f32, f32)> {
+    let [f1, f2, f3, f4] = parse_tuple(text, parse_float)?;
+    Some((f1, f2, f3, f4))
+}
+
+fn parse_placement(text: &str) -> Option<Placement> {
+    // Inverse of `write_float_vec8` in `bsii_output`: the binary form stores
+    // the position relative to a 512 unit grid cell, with the cell encoded in
+    // the fourth float.
+    let split = text.find(')')? + 1;
+    let [x, y, z] = parse_tuple(&text[..split], parse_float)?;
+    let (w, r1, r2, r3) = parse_float_vec4(&text[split..])?;
+    let cell_x = ((x / 512.0).round() as i32 + 2048) & 0xfff;
+    let cell_z = ((z / 512.0).round() as i32 + 2048) & 0xfff;
+    let x = x - ((cell_x - 2048) << 9) as f32;
+    let z = z - ((cell_z - 2048) << 9) as f32;
+    let coef = ((cell_z << 12) | cell_x) as f32;
+    Some((x, y, z, coef, w, r1, r2, r3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scalars_by_type() {
+        assert_eq!(
+            parse_scalar(0x01, None, "\"Hello there\""),
+            Some(DataValue::String("Hello there"))
+        );
+        assert_eq!(
+            parse_scalar(0x03, None, "berlin"),
+            Some(DataValue::EncodedString("berlin".to_string()))
+        );
+        assert_eq!(parse_scalar(0x03, None, "Berlin"), None);
+        assert_eq!(
+            parse_scalar(0x05, None, "&3fc00000"),
+            Some(DataValue::Float(1.5))
+        );
+        assert_eq!(parse_scalar(0x05, None, "2"), Some(DataValue::Float(2.0)));
+        assert_eq!(
+            parse_scalar(0x09, None, "(1, 2, &3fc00000)"),
+            Some(DataValue::FloatVec3((1.0, 2.0, 1.5)))
+        );
+        assert_eq!(parse_scalar(0x25, None, "-5"), Some(DataValue::Int32(-5)));
+        assert_eq!(
+            parse_scalar(0x27, None, "nil"),
+            Some(DataValue::UInt32(u32::MAX))
+        );
+        assert_eq!(parse_scalar(0x27, None, "-1"), None);
+        assert_eq!(
+            parse_scalar(0x35, None, "true"),
+            Some(DataValue::Bool(true))
+        );
+        assert_eq!(
+            parse_scalar(0x39, None, "_nameless.1a2.3b4c"),
+            Some(DataValue::Id(Id::Nameless(0x1a23b4c)))
+        );
+    }
+
+    #[test]
+    fn parses_enums_by_name_or_value() {
+        let values = HashMap::from([(0, "idle"), (3, "driving")]);
+
+        assert_eq!(
+            parse_scalar(0x37, Some(&values), "driving"),
+            Some(DataValue::Enum(3))
+        );
+        assert_eq!(
+            parse_scalar(0x37, Some(&values), "0"),
+            Some(DataValue::Enum(0))
+        );
+        assert_eq!(parse_scalar(0x37, Some(&values), "1"), None);
+    }
+
+    #[test]
+    fn parses_placements_written_by_output() {
+        let placement = (100.5, 2.0, -30.25, 8390656.0, 1.0, 0.0, 0.0, 0.0);
+        let DataValue::FloatVec8(parsed) =
+            parse_scalar(0x19, None, "(10000.5, 2, -3000.25) (1; 0, 0, 0)").unwrap()
+        else {
+            panic!("Expected a placement");
+        };
+
+        assert_eq!(
+            parsed.0 + (((parsed.3 as i32 & 0xfff) - 2048) << 9) as f32,
+            10000.5
+        );
+        assert_eq!(
+            parsed.2 + ((((parsed.3 as i32 >> 12) & 0xfff) - 2048) << 9) as f32,
+            -3000.25
+        );
+        assert_eq!(
+            parse_placement("(100.5, 2, -30.25) (1; 0, 0, 0)"),
+            Some(placement)
+        );
+    }
+
+    #[test]
+    fn replaces_array_elements_of_matching_type() {
+        let mut array = DataValue::Int32Array(vec![1, 2, 3]);
+
+        assert!(set_array_element(&mut array, 1, DataValue::Int32(5)));
+        assert!(!set_array_element(&mut array, 3, DataValue::Int32(5)));
+        assert!(!set_array_element(&mut array, 0, DataValue::Bool(true)));
+        assert_eq!(array, DataValue::Int32Array(vec![1, 5, 3]));
+        assert_eq!(array_element_type_id(0x26), Some(0x25));
+        assert_eq!(
+            collect_array(0x26, vec![DataValue::Int32(1), DataValue::Int32(2)]),
+            Some(DataValue::Int32Array(vec![1, 2]))
+        );
+        assert_eq!(collect_array(0x26, vec![DataValue::Bool(true)]), None);
+        assert_eq!(default_value(0x3a), Some(DataValue::IdArray(vec![])));
+        assert_eq!(array_element_type_id(0x25), None);
+    }
+}
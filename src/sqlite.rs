@@ -0,0 +1,443 @@
+//! Exports BSII files into a SQLite database.
+//!
+//! Every unit becomes a row of the table named after its prototype, with the
+//! columns `save_file` and `unit_id` followed by one column per scalar field.
+//! Array elements are stored in the `array_values` table, and every non-null
+//! ID stored in a field, including array elements, in the `unit_references`
+//! table. All rows are keyed by the name of the save file, so that the
+//! database can hold the history of many saves.
+//!
+//! Prototypes named like these tables or the internal tables of SQLite, and
+//! fields named `save_file` or `unit_id`, are rejected with
+//! [`ExportError::ReservedName`].
+
+use std::collections::HashMap;
+
+use rusqlite::types::Value;
+use rusqlite::{params, Connection, Transaction};
+
+use crate::bsii_file::{BsiiFile, DataValue, Id, Prototype, ValuePrototype};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS saves (
+    save_file TEXT PRIMARY KEY
+);
+CREATE TABLE IF NOT EXISTS array_values (
+    save_file TEXT NOT NULL,
+    unit_id TEXT NOT NULL,
+    prototype TEXT NOT NULL,
+    field TEXT NOT NULL,
+    idx INTEGER NOT NULL,
+    value
+);
+CREATE TABLE IF NOT EXISTS unit_references (
+    save_file TEXT NOT NULL,
+    unit_id TEXT NOT NULL,
+    prototype TEXT NOT NULL,
+    field TEXT NOT NULL,
+    idx INTEGER,
+    target_id TEXT NOT NULL
+);
+";
+
+/// The tables created by the exporter, which prototypes must not be named
+/// after.
+const RESERVED_TABLES: &[&str] = &["saves", "array_values", "unit_references"];
+/// The columns of every prototype table, which fields must not be named after.
+const RESERVED_COLUMNS: &[&str] = &["save_file", "unit_id"];
+
+#[derive(Debug)]
+pub enum ExportError {
+    Sqlite(rusqlite::Error),
+    /// A prototype or field name that collides with the tables or columns of
+    /// the exporter.
+    ReservedName(String),
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::Sqlite(err) => write!(f, "SQLite error: {}", err),
+            ExportError::ReservedName(name) => write!(f, "Reserved name `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<rusqlite::Error> for ExportError {
+    fn from(err: rusqlite::Error) -> Self {
+        ExportError::Sqlite(err)
+    }
+}
+
+/// Export all units of the file into the database, creating tables and
+/// columns as needed.
+///
+/// Rows previously exported with the same `save_file` are replaced, while rows
+/// of other save files are kept.
+pub fn export_to_sqlite(
+    connection: &mut Connection,
+    bsii: &BsiiFile<'_>,
+    save_file: &str,
+) -> Result<(), ExportError> {
+    let mut prototypes = bsii.prototypes().collect::<Vec<_>>();
+    prototypes.sort_by_key(|prototype| prototype.id());
+    for prototype in &prototypes {
+        check_names(prototype)?;
+    }
+
+    let transaction = connection.transaction()?;
+    transaction.execute_batch(SCHEMA)?;
+    remove_save(&transaction, save_file)?;
+    transaction.execute("INSERT INTO saves (save_file) VALUES (?1)", [save_file])?;
+
+    let mut insert_sql = HashMap::new();
+    for prototype in &prototypes {
+        create_table(&transaction, prototype)?;
+        insert_sql.insert(prototype.id(), insert_unit_sql(prototype));
+    }
+
+    for block in bsii.data_blocks() {
+        let (Some(prototype), Some(insert_sql)) = (
+            bsii.get_prototype(block.prototype_id),
+            insert_sql.get(&block.prototype_id),
+        ) else {
+            continue;
+        };
+        let unit_id = block.id.to_string();
+        let mut values = vec![
+            Value::from(save_file.to_string()),
+            Value::from(unit_id.clone()),
+        ];
+        for (value, value_prototype) in block.data.iter().zip(&prototype.value_prototypes) {
+            if is_array_field(value_prototype) {
+                for (index, element) in value.elements().iter().enumerate() {
+                    transaction
+                        .prepare_cached(
+                            "INSERT INTO array_values (save_file, unit_id, prototype, field, idx, value) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                        )?
+                        .execute(params![
+                            save_file,
+                            unit_id,
+                            prototype.name,
+                            value_prototype.name,
+                            index as i64,
+                            sql_value(element, value_prototype)
+                        ])?;
+                }
+            } else {
+                values.push(sql_value(value, value_prototype));
+            }
+            for (index, id) in referenced_ids(value) {
+                transaction
+                    .prepare_cached(
+                        "INSERT INTO unit_references (save_file, unit_id, prototype, field, idx, target_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    )?
+                    .execute(params![
+                        save_file,
+                        unit_id,
+                        prototype.name,
+                        value_prototype.name,
+                        index.map(|index| index as i64),
+                        id.to_string()
+                    ])?;
+            }
+        }
+        transaction
+            .prepare_cached(insert_sql)?
+            .execute(rusqlite::params_from_iter(values))?;
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+/// Reject the prototypes and fields that would collide with the tables and
+/// columns of the exporter. SQLite compares names case-insensitively.
+fn check_names(prototype: &Prototype<'_>) -> Result<(), ExportError> {
+    let is_reserved = |name: &str, reserved: &[&str]| {
+        reserved
+            .iter()
+            .any(|reserved| name.eq_ignore_ascii_case(reserved))
+    };
+    let is_internal = prototype
+        .name
+        .get(..7)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("sqlite_"));
+    if is_internal || is_reserved(prototype.name, RESERVED_TABLES) {
+        return Err(ExportError::ReservedName(prototype.name.to_string()));
+    }
+    match prototype
+        .fields()
+        .find(|value_prototype| is_reserved(value_prototype.name, RESERVED_COLUMNS))
+    {
+        Some(value_prototype) => Err(ExportError::ReservedName(format!(
+            "{}.{}",
+            prototype.name, value_prototype.name
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// The statement inserting a unit of a prototype: the save file and unit ID,
+/// followed by the scalar fields in prototype order.
+fn insert_unit_sql(prototype: &Prototype<'_>) -> String {
+    let columns = ["save_file".to_string(), "unit_id".to_string()]
+        .into_iter()
+        .chain(
+            prototype
+                .fields()
+                .filter(|value_prototype| !is_array_field(value_prototype))
+                .map(|value_prototype| quote(value_prototype.name)),
+        )
+        .collect::<Vec<_>>();
+    let placeholders = (1..=columns.len())
+        .map(|index| format!("?{index}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "INSERT INTO {} ({}) VALUES ({placeholders})",
+        quote(prototype.name),
+        columns.join(", ")
+    )
+}
+
+/// Delete all rows exported from a save file.
+fn remove_save(transaction: &Transaction<'_>, save_file: &str) -> rusqlite::Result<()> {
+    let tables = {
+        let mut statement = transaction
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name != 'saves'")?;
+        let tables = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        tables
+    };
+    for table in tables.iter().map(String::as_str).chain(["saves"]) {
+        transaction.execute(
+            &format!("DELETE FROM {} WHERE save_file = ?1", quote(table)),
+            [save_file],
+        )?;
+    }
+    Ok(())
+}
+
+/// Create the table of a prototype, or add the columns missing from a table
+/// created for an earlier save.
+fn create_table(transaction: &Transaction<'_>, prototype: &Prototype<'_>) -> rusqlite::Result<()> {
+    let table = quote(prototype.name);
+    transaction.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {table} (save_file TEXT NOT NULL, unit_id TEXT NOT NULL)"
+        ),
+        [],
+    )?;
+    let existing = {
+        let mut statement = transaction.prepare(&format!("PRAGMA table_info({table})"))?;
+        let columns = statement
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        columns
+    };
+    for value_prototype in prototype.fields() {
+        if is_array_field(value_prototype)
+            || existing.iter().any(|name| name == value_prototype.name)
+        {
+            continue;
+        }
+        transaction.execute(
+            &format!(
+                "ALTER TABLE {table} ADD COLUMN {} {}",
+                quote(value_prototype.name),
+                sql_type(value_prototype.type_id())
+            ),
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn is_array_field(value_prototype: &ValuePrototype<'_>) -> bool {
+    crate::siin_value::array_element_type_id(value_prototype.type_id()).is_some()
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn sql_type(type_id: u32) -> &'static str {
+    match type_id {
+        0x05 => "REAL",
+        0x25 | 0x27 | 0x2f | 0x2b | 0x31 | 0x33 | 0x35 => "INTEGER",
+        _ => "TEXT",
+    }
+}
+
+/// Convert a scalar value. Unsigned values equal to `nil` become `NULL`, and
+/// values without a matching SQL type are stored as plain text.
+fn sql_value(value: &DataValue<'_>, value_prototype: &ValuePrototype<'_>) -> Value {
+    match value {
+        DataValue::Float(f) => Value::Real(f64::from(*f)),
+        DataValue::Int32(i) => Value::Integer(i64::from(*i)),
+        DataValue::Int64(i) => Value::Integer(*i),
+        DataValue::UInt16(u16::MAX) | DataValue::UInt32(u32::MAX) | DataValue::UInt64(u64::MAX) => {
+            Value::Null
+        }
+        DataValue::UInt16(u) => Value::Integer(i64::from(*u)),
+        DataValue::UInt32(u) => Value::Integer(i64::from(*u)),
+        DataValue::UInt64(u) => match i64::try_from(*u) {
+            Ok(u) => Value::Integer(u),
+            Err(_) => Value::Text(u.to_string()),
+        },
+        DataValue::Bool(b) => Value::Integer(i64::from(*b)),
+        DataValue::Id(Id::Named(parts)) if parts.is_empty() => Value::Null,
+        value => Value::Text(value.to_plain_text(value_prototype)),
+    }
+}
+
+/// The non-null IDs stored in a value, with their index if it is an array.
+fn referenced_ids<'a>(value: &'a DataValue<'_>) -> Vec<(Option<usize>, &'a Id)> {
+    let ids = match value {
+        DataValue::Id(id) => vec![(None, id)],
+        DataValue::IdArray(ids) => ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (Some(index), id))
+            .collect(),
+        _ => vec![],
+    };
+    ids.into_iter()
+        .filter(|(_, id)| !matches!(id, Id::Named(parts) if parts.is_empty()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::bsii_file::DataBlock;
+
+    fn sample(money: i64) -> BsiiFile<'static> {
+        BsiiFile {
+            header: b"BSII",
+            version: 2,
+            prototypes: HashMap::from([(
+                1,
+                Prototype {
+                    id: 1,
+                    name: "bank",
+                    value_prototypes: vec![
+                        ValuePrototype {
+                            type_id: 0x31,
+                            name: "money_account",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x3a,
+                            name: "loans",
+                            enum_values: None,
+                        },
+                        ValuePrototype {
+                            type_id: 0x27,
+                            name: "overdraft_timer",
+                            enum_values: None,
+                        },
+                    ],
+                },
+            )]),
+            data_blocks: vec![DataBlock {
+                prototype_id: 1,
+                id: Id::Nameless(0x1234),
+                data: vec![
+                    DataValue::Int64(money),
+                    DataValue::IdArray(vec![Id::Nameless(0x10), Id::Named(vec![])]),
+                    DataValue::UInt32(u32::MAX),
+                ],
+            }],
+        }
+    }
+
+    fn count(connection: &Connection, sql: &str) -> i64 {
+        connection.query_row(sql, [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn exports_tables_arrays_and_references() {
+        let mut connection = Connection::open_in_memory().unwrap();
+
+        export_to_sqlite(&mut connection, &sample(500), "autosave").unwrap();
+
+        let (unit_id, money, timer): (String, i64, Option<i64>) = connection
+            .query_row(
+                "SELECT unit_id, money_account, overdraft_timer FROM bank",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            (unit_id.as_str(), money, timer),
+            ("_nameless.1234", 500, None)
+        );
+        assert_eq!(count(&connection, "SELECT COUNT(*) FROM array_values"), 2);
+        let target: String = connection
+            .query_row(
+                "SELECT target_id FROM unit_references WHERE field = 'loans' AND idx = 0",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(target, "_nameless.10");
+        assert_eq!(
+            count(&connection, "SELECT COUNT(*) FROM unit_references"),
+            1
+        );
+    }
+
+    #[test]
+    fn replaces_rows_of_the_same_save_file() {
+        let mut connection = Connection::open_in_memory().unwrap();
+
+        export_to_sqlite(&mut connection, &sample(500), "autosave").unwrap();
+        export_to_sqlite(&mut connection, &sample(700), "autosave").unwrap();
+        export_to_sqlite(&mut connection, &sample(900), "quicksave").unwrap();
+
+        assert_eq!(count(&connection, "SELECT COUNT(*) FROM saves"), 2);
+        assert_eq!(
+            count(&connection, "SELECT SUM(money_account) FROM bank"),
+            1600
+        );
+        assert_eq!(count(&connection, "SELECT COUNT(*) FROM array_values"), 4);
+    }
+
+    #[test]
+    fn rejects_reserved_names() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        let rename = |prototype: &'static str, field: &'static str| {
+            let mut bsii = sample(500);
+            let bank = bsii.prototypes.get_mut(&1).unwrap();
+            bank.name = prototype;
+            bank.value_prototypes[0].name = field;
+            bsii
+        };
+
+        for (prototype, field, name) in [
+            ("saves", "money_account", "saves"),
+            ("Array_Values", "money_account", "Array_Values"),
+            ("sqlite_master", "money_account", "sqlite_master"),
+            ("bank", "unit_id", "bank.unit_id"),
+            ("bank", "SAVE_FILE", "bank.SAVE_FILE"),
+        ] {
+            assert!(matches!(
+                export_to_sqlite(&mut connection, &rename(prototype, field), "autosave"),
+                Err(ExportError::ReservedName(reserved)) if reserved == name
+            ));
+        }
+        assert_eq!(
+            count(
+                &connection,
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'"
+            ),
+            0
+        );
+    }
+}
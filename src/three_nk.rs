@@ -0,0 +1,90 @@
+//! Decodes 3nK files, which scramble their content with a byte cipher.
+//!
+//! The file starts with the signature `3nK\x01`, a reserved byte and the seed
+//! of the cipher. Every following byte is XORed with a key derived from the
+//! seed plus the position of the byte, so encoding and decoding are the same
+//! operation.
+//!
+//! Reference:
+//! <https://github.com/TheLazyTomcat/SII_Decrypt/blob/master/Source/SII_3nK_Transcoder.pas>
+
+pub const SIGNATURE: &[u8] = b"3nK\x01";
+const HEADER_SIZE: usize = 6;
+
+#[derive(Debug)]
+pub enum ParseError {
+    InvalidHeader,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidHeader => write!(f, "Invalid header"),
+        }
+    }
+}
+
+/// Decode a 3nK file into its plain content.
+pub fn decode(content: &[u8]) -> Result<Vec<u8>, ParseError> {
+    if content.len() < HEADER_SIZE || !content.starts_with(SIGNATURE) {
+        return Err(ParseError::InvalidHeader);
+    }
+    let seed = content[HEADER_SIZE - 1];
+    Ok(transcode(&content[HEADER_SIZE..], seed))
+}
+
+/// Encode plain content into a 3nK file with the given seed.
+pub fn encode(content: &[u8], seed: u8) -> Vec<u8> {
+    let mut encoded = SIGNATURE.to_vec();
+    encoded.extend([0, seed]);
+    encoded.extend(transcode(content, seed));
+    encoded
+}
+
+fn transcode(content: &[u8], seed: u8) -> Vec<u8> {
+    content
+        .iter()
+        .enumerate()
+        .map(|(index, byte)| byte ^ key(seed.wrapping_add(index as u8)))
+        .collect()
+}
+
+fn key(k: u8) -> u8 {
+    (((k << 2) ^ (k ^ 0xff)) << 3) ^ k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_scrambled_content() {
+        let encoded = [
+            b'3', b'n', b'K', 0x01, // signature
+            0x00, // reserved
+            0x02, // seed
+            0xf9, 0xea, 0x35, 0x3b, // scrambled "SiiN"
+        ];
+
+        assert_eq!(decode(&encoded).unwrap(), b"SiiN");
+    }
+
+    #[test]
+    fn round_trips_with_wrapping_seed() {
+        let content = (0..=255).collect::<Vec<u8>>();
+
+        let encoded = encode(&content, 0xfe);
+
+        assert_eq!(&encoded[..6], b"3nK\x01\x00\xfe");
+        assert_ne!(&encoded[6..], content);
+        assert_eq!(decode(&encoded).unwrap(), content);
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        assert!(matches!(
+            decode(b"3nK\x01\x00"),
+            Err(ParseError::InvalidHeader)
+        ));
+    }
+}
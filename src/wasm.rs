@@ -1,6 +1,6 @@
 use wasm_bindgen::{prelude::wasm_bindgen, JsError};
 
-use crate::ets2::analyze_save_to_json;
+use crate::ets2::{analyze_save_to_json, load_profile};
 use crate::file_type::decode_until_siin;
 
 #[wasm_bindgen]
@@ -19,3 +19,10 @@ pub fn decode(input: &[u8]) -> Result<String, JsError> {
 pub fn analyze_ets2_save(input: &[u8]) -> Result<String, JsError> {
     analyze_save_to_json(input).map_err(|err| JsError::new(&err.to_string()))
 }
+
+#[wasm_bindgen]
+pub fn read_ets2_profile(input: &[u8]) -> Result<String, JsError> {
+    load_profile(input)
+        .map(|profile| profile.to_json())
+        .map_err(|err| JsError::new(&err.to_string()))
+}
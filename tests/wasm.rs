@@ -1,7 +1,7 @@
 #[cfg(feature = "wasm")]
 mod wasm_test {
 
-    use sii_decode::wasm::{analyze_ets2_save, decode};
+    use sii_decode::wasm::{analyze_ets2_save, decode, read_ets2_profile};
     use wasm_bindgen_test::wasm_bindgen_test;
 
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -26,4 +26,10 @@ mod wasm_test {
         let input = b"SiiNunit\n{\n}\n";
         analyze_ets2_save(input).expect_err("structured analysis should reject textual SiiN");
     }
+
+    #[wasm_bindgen_test]
+    fn test_read_ets2_profile_with_failure() {
+        let input = b"Invalid data";
+        read_ets2_profile(input).expect_err("profile reading should reject unknown files");
+    }
 }
@@ -6,6 +6,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 
 const RUST_OUTPUT: &str = "src/ets2/generated/cargo_metadata.rs";
+const CITY_RUST_OUTPUT: &str = "src/ets2/generated/city_metadata.rs";
 const JSON_OUTPUT: &str = "target/ets2-defs/cargo_metadata.json";
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -17,6 +18,14 @@ struct CargoMetadata {
     fragility: Option<String>,
     body_types: Vec<String>,
     trailer_categories: Vec<String>,
+    unit_reward_per_km: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct CityMetadata {
+    id: String,
+    name: String,
+    country: String,
 }
 
 #[derive(Debug)]
@@ -71,11 +80,16 @@ fn run(args: impl IntoIterator<Item = String>) -> Result<(), Error> {
         "extract-ets2-defs" => {
             let defs = parse_defs_arg(args)?;
             let cargo_metadata = extract_cargo_metadata(&defs)?;
-            write_generated_files(&cargo_metadata)?;
+            let city_metadata = extract_city_metadata(&defs)?;
+            write_generated_files(&cargo_metadata, &city_metadata)?;
             println!(
                 "extracted {} cargo definitions to {RUST_OUTPUT}",
                 cargo_metadata.len()
             );
+            println!(
+                "extracted {} city definitions to {CITY_RUST_OUTPUT}",
+                city_metadata.len()
+            );
             Ok(())
         }
         _ => Err(Error::Usage(usage().to_string())),
@@ -138,6 +152,7 @@ fn parse_cargo_file(
     let mut adr_class = None;
     let mut fragility = None;
     let mut body_types = BTreeSet::new();
+    let mut unit_reward_per_km = None;
 
     for line in normalized_lines(content) {
         if let Some(value) = line.strip_prefix("cargo_data:") {
@@ -154,6 +169,8 @@ fn parse_cargo_file(
             fragility = Some(parse_value(value.trim()).to_string());
         } else if let Some(value) = line.strip_prefix("body_types[]:") {
             body_types.insert(parse_value(value.trim()).to_string());
+        } else if let Some(value) = line.strip_prefix("unit_reward_per_km:") {
+            unit_reward_per_km = Some(parse_value(value.trim()).to_string());
         }
     }
 
@@ -175,18 +192,71 @@ fn parse_cargo_file(
         fragility,
         body_types,
         trailer_categories,
+        unit_reward_per_km,
     })
 }
 
+fn extract_city_metadata(defs: &Path) -> Result<Vec<CityMetadata>, Error> {
+    let city_dir = defs.join("city");
+    let mut city_paths = fs::read_dir(&city_dir)
+        .map_err(|source| Error::io(&city_dir, source))?
+        .map(|entry| {
+            entry
+                .map(|entry| entry.path())
+                .map_err(|source| Error::io(&city_dir, source))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    city_paths.retain(|path| {
+        path.extension() == Some(OsStr::new("sui")) || path.extension() == Some(OsStr::new("sii"))
+    });
+    city_paths.sort();
+
+    let mut cities = Vec::new();
+    for path in city_paths {
+        let content = fs::read_to_string(&path).map_err(|source| Error::io(&path, source))?;
+        if let Some(city) = parse_city_file(&path, &content)? {
+            cities.push(city);
+        }
+    }
+    cities.sort_by(|left, right| left.id.cmp(&right.id));
+    Ok(cities)
+}
+
+/// Parse a city definition, or return `None` for files in the city directory
+/// that define no city.
+fn parse_city_file(path: &Path, content: &str) -> Result<Option<CityMetadata>, Error> {
+    let mut id = None;
+    let mut name = None;
+    let mut country = None;
+
+    for line in normalized_lines(content) {
+        if let Some(value) = line.strip_prefix("city_data:") {
+            id = Some(parse_city_id(value.trim()).ok_or_else(|| {
+                Error::parse(path, format!("invalid city_data declaration `{}`", line))
+            })?);
+        } else if let Some(value) = line.strip_prefix("city_name:") {
+            name = Some(parse_value(value.trim()).to_string());
+        } else if let Some(value) = line.strip_prefix("country:") {
+            country = Some(parse_value(value.trim()).to_string());
+        }
+    }
+
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    let name = name.ok_or_else(|| Error::parse(path, "missing city name"))?;
+    let country = country.ok_or_else(|| Error::parse(path, "missing city country"))?;
+    Ok(Some(CityMetadata { id, name, country }))
+}
+
 fn parse_body_type_categories(path: &Path) -> Result<BTreeMap<String, String>, Error> {
     let content = fs::read_to_string(path).map_err(|source| Error::io(path, source))?;
     let mut body_type_categories = BTreeMap::new();
     let mut current_category = None::<String>;
 
     for line in normalized_lines(&content) {
-        if line.starts_with("trailer_category_def:") {
-            current_category = None;
-        } else if line == "}" {
+        if line.starts_with("trailer_category_def:") || line == "}" {
             current_category = None;
         } else if let Some(value) = line.strip_prefix("trailer_category:") {
             current_category = Some(parse_value(value.trim()).to_string());
@@ -238,6 +308,13 @@ fn parse_cargo_id(value: &str) -> Option<String> {
         .map(ToString::to_string)
 }
 
+fn parse_city_id(value: &str) -> Option<String> {
+    value
+        .strip_prefix("city.")
+        .filter(|id| !id.is_empty())
+        .map(ToString::to_string)
+}
+
 fn parse_value(value: &str) -> &str {
     value
         .strip_prefix('"')
@@ -245,13 +322,17 @@ fn parse_value(value: &str) -> &str {
         .unwrap_or(value)
 }
 
-fn write_generated_files(cargos: &[CargoMetadata]) -> Result<(), Error> {
+fn write_generated_files(cargos: &[CargoMetadata], cities: &[CityMetadata]) -> Result<(), Error> {
     let rust_output = Path::new(RUST_OUTPUT);
     if let Some(parent) = rust_output.parent() {
         fs::create_dir_all(parent).map_err(|source| Error::io(parent, source))?;
     }
     fs::write(rust_output, render_rust(cargos)).map_err(|source| Error::io(rust_output, source))?;
 
+    let city_rust_output = Path::new(CITY_RUST_OUTPUT);
+    fs::write(city_rust_output, render_city_rust(cities))
+        .map_err(|source| Error::io(city_rust_output, source))?;
+
     let json_output = Path::new(JSON_OUTPUT);
     if let Some(parent) = json_output.parent() {
         fs::create_dir_all(parent).map_err(|source| Error::io(parent, source))?;
@@ -295,6 +376,33 @@ fn render_rust(cargos: &[CargoMetadata]) -> String {
             "        trailer_categories: {},\n",
             render_rust_str_slice(&cargo.trailer_categories)
         ));
+        output.push_str(&format!(
+            "        unit_reward_per_km: {},\n",
+            render_rust_option(&cargo.unit_reward_per_km)
+        ));
+        output.push_str("    },\n");
+    }
+
+    output.push_str("];\n");
+    output
+}
+
+fn render_city_rust(cities: &[CityMetadata]) -> String {
+    let mut output = String::from(
+        "// @generated by `cargo xtask extract-ets2-defs --defs def/def`\n\
+         // Do not edit by hand.\n\n\
+         use crate::ets2::CityMetadata;\n\n\
+         pub const CITIES: &[CityMetadata] = &[\n",
+    );
+
+    for city in cities {
+        output.push_str("    CityMetadata {\n");
+        output.push_str(&format!("        id: \"{}\",\n", rust_escape(&city.id)));
+        output.push_str(&format!("        name: \"{}\",\n", rust_escape(&city.name)));
+        output.push_str(&format!(
+            "        country: \"{}\",\n",
+            rust_escape(&city.country)
+        ));
         output.push_str("    },\n");
     }
 
@@ -351,9 +459,13 @@ fn render_json(cargos: &[CargoMetadata]) -> String {
             render_json_array(&cargo.body_types)
         ));
         output.push_str(&format!(
-            "      \"trailer_categories\": {}\n",
+            "      \"trailer_categories\": {},\n",
             render_json_array(&cargo.trailer_categories)
         ));
+        output.push_str(&format!(
+            "      \"unit_reward_per_km\": {}\n",
+            render_json_option(&cargo.unit_reward_per_km)
+        ));
         output.push_str("    }");
     }
     output.push_str("\n  ]\n}\n");
@@ -411,6 +523,7 @@ mod tests {
                 name: "@@cn_apples@@"
                 adr_class: 3
                 fragility: 0.35
+                unit_reward_per_km: 0.82
                 group[]: refrigerated
                 group[]: containers
                 body_types[]: curtainside
@@ -428,6 +541,38 @@ mod tests {
         assert_eq!(cargo.fragility.as_deref(), Some("0.35"));
         assert_eq!(cargo.body_types, ["curtainside", "dryvan"]);
         assert_eq!(cargo.trailer_categories, ["dryvan"]);
+        assert_eq!(cargo.unit_reward_per_km.as_deref(), Some("0.82"));
+    }
+
+    #[test]
+    fn parses_inline_city_snippet() {
+        let city = parse_city_file(
+            Path::new("def/def/city/berlin.sui"),
+            r#"
+            city_data: city.berlin
+            {
+                city_name: "Berlin"
+                short_city_name: "@@berlin@@" # not the name
+                country: germany
+                time_zone: 60
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            city,
+            Some(CityMetadata {
+                id: "berlin".to_string(),
+                name: "Berlin".to_string(),
+                country: "germany".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_city_file(Path::new("def/def/city/readme.sii"), "SiiNunit\n{\n}\n").unwrap(),
+            None
+        );
+        assert!(render_city_rust(&[city.unwrap()]).contains("        country: \"germany\",\n"));
     }
 
     #[test]